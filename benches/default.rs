@@ -0,0 +1,23 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use ssz_types::{typenum::U1048576, FixedVector};
+use std::hint::black_box;
+
+type Vector = FixedVector<u64, U1048576>;
+
+/// Compares `FixedVector::default` against building the backing `Vec` one default at a time.
+fn default(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fixed_vector_default_1m");
+
+    group.bench_function("default", |b| b.iter(|| black_box(Vector::default())));
+    group.bench_function("map_collect", |b| {
+        b.iter(|| {
+            let vec: Vec<u64> = (0..1 << 20).map(|_| u64::default()).collect();
+            black_box(Vector::from(vec))
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, default);
+criterion_main!(benches);
@@ -0,0 +1,1236 @@
+//! Operations on `BitList` and `BitVector`, which are defined in `ethereum_ssz`.
+//!
+//! These are implemented in terms of the public `Bitfield::len` and `Bitfield::as_slice`, relying
+//! on `Bitfield` always keeping the bits beyond `len()` cleared. Functions which modify a bitfield
+//! rebuild it from its bytes.
+//!
+//! `to_byte_array` and `from_byte_array` convert a `BitVector` to and from its SSZ bytes as a
+//! fixed-size array, avoiding the allocation of `into_bytes` when the byte length is known
+//! statically.
+use crate::bitlist_bytes::from_raw_bytes_lenient;
+use crate::length::{Fixed, Variable};
+use crate::{BitList, BitVector, Bitfield, Error};
+use itertools::{EitherOrBoth, Itertools};
+use smallvec::SmallVec;
+use std::cmp::Ordering;
+use tree_hash::{Hash256, TreeHash, TreeHashType, BYTES_PER_CHUNK};
+use typenum::{IsGreaterOrEqual, True, Unsigned};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Implemented for `BitList` and `BitVector`, so that the functions in this module accept either.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait AnyBitfield: Clone + sealed::Sealed {
+    /// Returns a bitfield of length `len` with no bits set.
+    ///
+    /// Returns `Error::OutOfBounds` if `len` exceeds `N` for a `BitList<N>` or is not `N` for a
+    /// `BitVector<N>`.
+    fn zeroed(len: usize) -> Result<Self, Error>;
+
+    /// Returns the number of bits, as per `Bitfield::len`.
+    fn bit_len(&self) -> usize;
+
+    /// Returns the underlying bytes, as per `Bitfield::as_slice`.
+    fn bytes(&self) -> &[u8];
+
+    /// Replaces the underlying bytes, keeping the length.
+    ///
+    /// Returns `Err` if `bytes` is not the same length as `self.bytes()` or if any bit at a
+    /// position `>= self.bit_len()` is set. `self` is unchanged on error.
+    fn replace_bytes(&mut self, bytes: &[u8]) -> Result<(), Error>;
+}
+
+macro_rules! impl_any_bitfield {
+    ($behaviour: ident, $zeroed: expr, $from_bytes: expr) => {
+        impl<N: Unsigned + Clone> sealed::Sealed for Bitfield<$behaviour<N>> {}
+
+        impl<N: Unsigned + Clone> AnyBitfield for Bitfield<$behaviour<N>> {
+            fn zeroed(len: usize) -> Result<Self, Error> {
+                $zeroed(len)
+            }
+
+            fn bit_len(&self) -> usize {
+                self.len()
+            }
+
+            fn bytes(&self) -> &[u8] {
+                self.as_slice()
+            }
+
+            fn replace_bytes(&mut self, bytes: &[u8]) -> Result<(), Error> {
+                if bytes.len() != self.as_slice().len() {
+                    return Err(Error::InvalidByteCount {
+                        given: bytes.len(),
+                        expected: self.as_slice().len(),
+                    });
+                }
+                *self = $from_bytes(bytes, self.len())?;
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_any_bitfield!(
+    Variable,
+    |len| Bitfield::<Variable<N>>::with_capacity(len).map_err(Error::from),
+    from_raw_bytes_lenient::<N>
+);
+impl_any_bitfield!(
+    Fixed,
+    |len| {
+        if len == N::to_usize() {
+            Ok(Bitfield::<Fixed<N>>::new())
+        } else {
+            Err(Error::OutOfBounds {
+                i: len,
+                len: N::to_usize(),
+            })
+        }
+    },
+    |bytes: &[u8], _| Bitfield::<Fixed<N>>::from_bytes(SmallVec::from_slice(bytes))
+);
+
+/// Returns bit `i` of `bytes`, numbering the bits within each byte least-significant-first.
+fn bit_at(bytes: &[u8], i: usize) -> bool {
+    bytes[i / 8] & (1 << (i % 8)) != 0
+}
+
+/// Clears the bits at positions `>= len` in the final byte of `bytes`.
+fn clear_excess_bits(bytes: &mut [u8], len: usize) {
+    let bits_in_last_byte = len.saturating_sub(bytes.len().saturating_sub(1) * 8).min(8);
+    if let Some(last) = bytes.last_mut() {
+        *last &= ((1_u16 << bits_in_last_byte) - 1) as u8;
+    }
+}
+
+/// Returns a bitfield of length `len` with the bits at `indices` set.
+///
+/// Returns `Error::OutOfBounds` if any index is not less than `len`.
+fn from_set_indices<B: AnyBitfield>(
+    len: usize,
+    indices: impl IntoIterator<Item = usize>,
+) -> Result<B, Error> {
+    let mut bitfield = B::zeroed(len)?;
+    let mut bytes = bitfield.bytes().to_vec();
+    for i in indices {
+        if i >= len {
+            return Err(Error::OutOfBounds { i, len });
+        }
+        bytes[i / 8] |= 1 << (i % 8);
+    }
+    bitfield.replace_bytes(&bytes)?;
+    Ok(bitfield)
+}
+
+/// Returns a bitfield of length `len` with every bit set.
+///
+/// Bits beyond `len` in the final byte are left cleared. Returns `Error::OutOfBounds` if `len`
+/// exceeds `N` for a `BitList<N>` or is not `N` for a `BitVector<N>`.
+pub fn new_full<B: AnyBitfield>(len: usize) -> Result<B, Error> {
+    from_set_indices(len, 0..len)
+}
+
+/// Accumulates the indices of set bits, in any order and with duplicates, then builds a
+/// `BitList<N>` once its length is known.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BitListBuilder<N: Unsigned + Clone> {
+    bits: BitList<N>,
+}
+
+impl<N: Unsigned + Clone> BitListBuilder<N> {
+    /// Returns a builder with no bits set.
+    pub fn new() -> Self {
+        Self {
+            bits: BitList::with_capacity(N::to_usize()).expect("a BitList<N> can hold N bits"),
+        }
+    }
+
+    /// Records bit `index` as set. Setting the same index again has no effect.
+    ///
+    /// Returns `Error::OutOfBounds` if `index` is not less than `N`.
+    pub fn set(&mut self, index: usize) -> Result<(), Error> {
+        self.bits.set(index, true).map_err(Error::from)
+    }
+
+    /// Returns a `BitList<N>` of length `len` with the recorded bits set.
+    ///
+    /// Returns `Error::OutOfBounds` if `len` exceeds `N` or if a recorded index is not less than
+    /// `len`, in which case `i` is the highest recorded index.
+    pub fn finish(self, len: usize) -> Result<BitList<N>, Error> {
+        match self.bits.highest_set_bit() {
+            Some(i) if i >= len && len <= N::to_usize() => Err(Error::OutOfBounds { i, len }),
+            _ => from_raw_bytes_lenient(self.bits.as_slice(), len),
+        }
+    }
+}
+
+impl<N: Unsigned + Clone> Default for BitListBuilder<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the 32-byte leaves which `tree_hash_root` merkleizes for `bitfield`, e.g. for building
+/// multiproofs.
+///
+/// These are the bytes of `bitfield` split into chunks, with the final chunk zero-padded. For a
+/// `BitList`, they are followed by the length leaf which `tree_hash::mix_in_length` hashes with
+/// the root of the chunks. The zero leaves which pad the tree up to the capacity `N` are not
+/// included.
+pub fn tree_hash_chunks<B: AnyBitfield + TreeHash>(bitfield: &B) -> Vec<Hash256> {
+    let mut chunks: Vec<Hash256> = bitfield
+        .bytes()
+        .chunks(BYTES_PER_CHUNK)
+        .map(|chunk| {
+            let mut leaf = [0; BYTES_PER_CHUNK];
+            leaf[..chunk.len()].copy_from_slice(chunk);
+            Hash256::from(leaf)
+        })
+        .collect();
+
+    if B::tree_hash_type() == TreeHashType::List {
+        let mut leaf = [0; BYTES_PER_CHUNK];
+        leaf[..std::mem::size_of::<usize>()].copy_from_slice(&bitfield.bit_len().to_le_bytes());
+        chunks.push(Hash256::from(leaf));
+    }
+    chunks
+}
+
+/// Shortens `bitlist` to `new_len`, discarding the bits at positions `>= new_len`.
+///
+/// Has no effect if `new_len >= bitlist.len()`.
+pub fn truncate<N: Unsigned + Clone>(bitlist: &mut BitList<N>, new_len: usize) {
+    if new_len >= bitlist.len() {
+        return;
+    }
+
+    let mut bytes = bitlist.as_slice()[..std::cmp::max(1, new_len.div_ceil(8))].to_vec();
+    clear_excess_bits(&mut bytes, new_len);
+    *bitlist = from_raw_bytes_lenient(&bytes, new_len)
+        .expect("truncated bytes are valid for a shorter length");
+}
+
+/// Returns the bits of `bitfield` in order, i.e., a `Vec` of length `len()`.
+pub fn to_bool_vec<B: AnyBitfield>(bitfield: &B) -> Vec<bool> {
+    (0..bitfield.bit_len())
+        .map(|i| bit_at(bitfield.bytes(), i))
+        .collect()
+}
+
+/// Builds a bitfield whose bits are `bits`, as per `to_bool_vec`.
+///
+/// Returns `Error::OutOfBounds` if `bits` is longer than `N` for a `BitList<N>` or is not `N` long
+/// for a `BitVector<N>`.
+pub fn try_from_bool_slice<B: AnyBitfield>(bits: &[bool]) -> Result<B, Error> {
+    from_set_indices(bits.len(), bits.iter().positions(|&bit| bit))
+}
+
+/// Returns the SSZ bytes of `bitvector` as an array of `M` bytes.
+///
+/// Returns `Err` if `M` is not `ceil(N / 8)`.
+pub fn to_byte_array<N: Unsigned + Clone, const M: usize>(
+    bitvector: &BitVector<N>,
+) -> Result<[u8; M], Error> {
+    let bytes = bitvector.as_slice();
+    bytes.try_into().map_err(|_| Error::InvalidByteCount {
+        given: M,
+        expected: bytes.len(),
+    })
+}
+
+/// Builds a `BitVector<N>` from its SSZ bytes, as per `to_byte_array`.
+///
+/// Returns `Err` if `M` is not `ceil(N / 8)` or if any bit at a position `>= N` is set.
+pub fn from_byte_array<N: Unsigned + Clone, const M: usize>(
+    bytes: &[u8; M],
+) -> Result<BitVector<N>, Error> {
+    BitVector::from_bytes(SmallVec::from_slice(bytes)).map_err(Error::from)
+}
+
+/// Returns `bitvector` as a `BitVector<M>`, with the bits at positions `>= N` cleared.
+pub fn widen<N, M>(bitvector: &BitVector<N>) -> BitVector<M>
+where
+    N: Unsigned + Clone,
+    M: Unsigned + Clone + IsGreaterOrEqual<N, Output = True>,
+{
+    let mut widened = BitVector::<M>::new();
+    let mut bytes = widened.as_slice().to_vec();
+    bytes[..bitvector.as_slice().len()].copy_from_slice(bitvector.as_slice());
+    widened
+        .replace_bytes(&bytes)
+        .expect("bits below N are below M");
+    widened
+}
+
+/// Returns `bitvector` as a `BitVector<M>`, dropping the bits at positions `>= M`.
+///
+/// Returns `Error::OutOfBounds { i, len: M }` if any dropped bit is set, where `i` is the highest
+/// set bit.
+pub fn narrow<N, M>(bitvector: &BitVector<N>) -> Result<BitVector<M>, Error>
+where
+    N: Unsigned + Clone + IsGreaterOrEqual<M, Output = True>,
+    M: Unsigned + Clone,
+{
+    if let Some(i) = bitvector.highest_set_bit().filter(|&i| i >= M::to_usize()) {
+        return Err(Error::OutOfBounds {
+            i,
+            len: M::to_usize(),
+        });
+    }
+
+    let mut narrowed = BitVector::<M>::new();
+    let len = narrowed.as_slice().len();
+    narrowed.replace_bytes(&bitvector.as_slice()[..len])?;
+    Ok(narrowed)
+}
+
+/// Returns `true` if every bit below `len()` is set.
+///
+/// Vacuously `true` for a bitfield of length zero.
+pub fn is_all_set<B: AnyBitfield>(bitfield: &B) -> bool {
+    let len = bitfield.bit_len();
+    let (full, partial) = bitfield.bytes().split_at(len / 8);
+    let partial_mask = (1_u8 << (len % 8)).wrapping_sub(1);
+
+    full.iter().all(|&byte| byte == u8::MAX) && partial.iter().all(|&byte| byte == partial_mask)
+}
+
+/// Returns the number of set bits, as per `Bitfield::num_set_bits`.
+fn weight<B: AnyBitfield>(bitfield: &B) -> usize {
+    bitfield
+        .bytes()
+        .iter()
+        .map(|byte| byte.count_ones() as usize)
+        .sum()
+}
+
+/// Compares `a` and `b` by their number of set bits, ignoring which bits are set.
+pub fn cmp_by_weight<B: AnyBitfield>(a: &B, b: &B) -> Ordering {
+    weight(a).cmp(&weight(b))
+}
+
+/// Returns the bitfield with the most set bits, or `None` if `bitfields` is empty.
+///
+/// Of several bitfields with the same, highest number of set bits, returns the first.
+pub fn max_by_weight<'a, B, I>(bitfields: I) -> Option<&'a B>
+where
+    B: AnyBitfield + 'a,
+    I: IntoIterator<Item = &'a B>,
+{
+    bitfields
+        .into_iter()
+        .map(|bitfield| (weight(bitfield), bitfield))
+        .reduce(|max, next| if next.0 > max.0 { next } else { max })
+        .map(|(_, bitfield)| bitfield)
+}
+
+/// Returns the number of bit positions at which `a` and `b` differ.
+///
+/// If the lengths differ, the missing bits of the shorter bitfield are treated as unset, as per
+/// `BitList::union`. Counts whole bytes without allocating.
+pub fn hamming_distance<B: AnyBitfield>(a: &B, b: &B) -> usize {
+    a.bytes()
+        .iter()
+        .zip_longest(b.bytes())
+        .map(|pair| match pair {
+            EitherOrBoth::Both(x, y) => (x ^ y).count_ones() as usize,
+            EitherOrBoth::Left(x) | EitherOrBoth::Right(x) => x.count_ones() as usize,
+        })
+        .sum()
+}
+
+/// Returns the number of bits set in either `a` or `b`, i.e., `a.union(b).num_set_bits()` without
+/// allocating the union.
+///
+/// If the lengths differ, the missing bits of the shorter bitfield are treated as unset, as per
+/// `BitList::union`.
+pub fn union_count<B: AnyBitfield>(a: &B, b: &B) -> usize {
+    a.bytes()
+        .iter()
+        .zip_longest(b.bytes())
+        .map(|pair| match pair {
+            EitherOrBoth::Both(x, y) => (x | y).count_ones() as usize,
+            EitherOrBoth::Left(x) | EitherOrBoth::Right(x) => x.count_ones() as usize,
+        })
+        .sum()
+}
+
+/// Returns the union of all `bitfields`, each of which must have length `len`.
+///
+/// Allocates the result once and ORs each input into it, rather than building an intermediate
+/// union per input. Returns a bitfield with no bits set if `bitfields` is empty. Returns
+/// `Error::OutOfBounds { i, len }` if an input's length `i` is not `len`.
+pub fn union_many<'a, B, I>(len: usize, bitfields: I) -> Result<B, Error>
+where
+    B: AnyBitfield + 'a,
+    I: IntoIterator<Item = &'a B>,
+{
+    let mut union = B::zeroed(len)?;
+    let mut bytes = union.bytes().to_vec();
+    for bitfield in bitfields {
+        if bitfield.bit_len() != len {
+            return Err(Error::OutOfBounds {
+                i: bitfield.bit_len(),
+                len,
+            });
+        }
+        for (byte, other) in bytes.iter_mut().zip(bitfield.bytes()) {
+            *byte |= other;
+        }
+    }
+    union.replace_bytes(&bytes)?;
+    Ok(union)
+}
+
+/// Returns an iterator over the indices, in ascending order, of the bits set in `new` but not in
+/// `prev`.
+///
+/// Bits of `new` beyond `prev.len()` are treated as unset in `prev`. Each byte is masked once and
+/// its set bits visited with `trailing_zeros`, so unset regions are skipped cheaply.
+pub fn newly_set<'a, B: AnyBitfield>(new: &'a B, prev: &'a B) -> impl Iterator<Item = usize> + 'a {
+    let prev = prev.bytes();
+    new.bytes()
+        .iter()
+        .enumerate()
+        .flat_map(move |(byte_index, &byte)| {
+            set_bit_indices(
+                byte_index,
+                byte & !prev.get(byte_index).copied().unwrap_or(0),
+            )
+        })
+}
+
+/// Returns an iterator over the indices, in ascending order, of the bits in `0..len()` which are
+/// not set.
+///
+/// Each byte is inverted and its set bits visited with `trailing_zeros`. The bits at positions
+/// `>= len()` in the final byte are never yielded.
+pub fn iter_zeros<B: AnyBitfield>(bitfield: &B) -> impl Iterator<Item = usize> + '_ {
+    let len = bitfield.bit_len();
+    bitfield
+        .bytes()
+        .iter()
+        .enumerate()
+        .flat_map(move |(byte_index, &byte)| {
+            let mut inverted = [!byte];
+            clear_excess_bits(&mut inverted, len.saturating_sub(byte_index * 8));
+            set_bit_indices(byte_index, inverted[0])
+        })
+}
+
+/// Returns the length of `bitfield` and the indices of its set bits in ascending order, a compact
+/// storage format for sparse bitfields. This is not SSZ.
+///
+/// ## Panics
+///
+/// If a set bit's index does not fit in a `u32`.
+pub fn to_compact_indices<B: AnyBitfield>(bitfield: &B) -> (usize, Vec<u32>) {
+    let indices = bitfield
+        .bytes()
+        .iter()
+        .enumerate()
+        .flat_map(|(byte_index, &byte)| set_bit_indices(byte_index, byte))
+        .map(|i| u32::try_from(i).expect("bit index should fit in a u32"))
+        .collect();
+    (bitfield.bit_len(), indices)
+}
+
+/// Builds a bitfield of length `len` with the bits at `indices` set, as per `to_compact_indices`.
+///
+/// Returns `Error::OutOfBounds` if any index is not less than `len`, or if `len` exceeds `N` for a
+/// `BitList<N>` or is not `N` for a `BitVector<N>`.
+pub fn from_compact_indices<B: AnyBitfield>(len: usize, indices: &[u32]) -> Result<B, Error> {
+    from_set_indices(len, indices.iter().map(|&i| i as usize))
+}
+
+/// Returns `bitfield` with every bit in `0..len()` flipped, keeping its length.
+///
+/// The bits at positions `>= len()` in the final byte stay cleared. `std::ops::Not` can't be
+/// implemented here since `Bitfield` is defined in `ethereum_ssz`.
+pub fn complement<B: AnyBitfield>(bitfield: &B) -> B {
+    let mut bytes: Vec<u8> = bitfield.bytes().iter().map(|byte| !byte).collect();
+    clear_excess_bits(&mut bytes, bitfield.bit_len());
+
+    let mut complement = bitfield.clone();
+    complement
+        .replace_bytes(&bytes)
+        .expect("complemented bits are within len");
+    complement
+}
+
+/// Reverses the order of the bits in `0..len()`, so that bit `i` moves to `len() - 1 - i`.
+///
+/// The bits at positions `>= len()` in the final byte stay cleared.
+pub fn reverse_bits<B: AnyBitfield>(bitfield: &mut B) {
+    let len = bitfield.bit_len();
+    let mut bytes = vec![0; bitfield.bytes().len()];
+    for i in bitfield
+        .bytes()
+        .iter()
+        .enumerate()
+        .flat_map(|(byte_index, &byte)| set_bit_indices(byte_index, byte))
+    {
+        let j = len - 1 - i;
+        bytes[j / 8] |= 1 << (j % 8);
+    }
+    bitfield
+        .replace_bytes(&bytes)
+        .expect("reversed bits are within len");
+}
+
+/// Returns an iterator over the indices of the set bits of `word`, the byte at `byte_index`.
+fn set_bit_indices(byte_index: usize, mut word: u8) -> impl Iterator<Item = usize> {
+    std::iter::from_fn(move || {
+        if word == 0 {
+            return None;
+        }
+        let bit = word.trailing_zeros() as usize;
+        word &= word - 1;
+        Some(byte_index * 8 + bit)
+    })
+}
+
+/// Copies `len` bits from `src[src_start..]` into `dst[dst_start..]`.
+///
+/// Returns `Error::OutOfBounds` if either range extends beyond its bitfield's length, in which case
+/// `dst` is unchanged. The bits are copied a byte at a time, shifting `src` where `dst_start % 8`
+/// and `src_start % 8` differ.
+pub fn copy_bits_from<B: AnyBitfield>(
+    dst: &mut B,
+    dst_start: usize,
+    src: &B,
+    src_start: usize,
+    len: usize,
+) -> Result<(), Error> {
+    for (start, bitfield_len) in [(dst_start, dst.bit_len()), (src_start, src.bit_len())] {
+        match start.checked_add(len) {
+            Some(end) if end <= bitfield_len => {}
+            end => {
+                return Err(Error::OutOfBounds {
+                    i: end.unwrap_or(usize::MAX),
+                    len: bitfield_len,
+                })
+            }
+        }
+    }
+
+    let src = src.bytes();
+    let mut bytes = dst.bytes().to_vec();
+    let mut copied = 0;
+    while copied < len {
+        let (d, s) = (dst_start + copied, src_start + copied);
+        // Copy up to the next byte boundary in `dst`.
+        let n = std::cmp::min(8 - d % 8, len - copied);
+        let window =
+            u16::from(src[s / 8]) | u16::from(src.get(s / 8 + 1).copied().unwrap_or(0)) << 8;
+        let bits = (window >> (s % 8)) as u8;
+        let mask = ((1_u16 << n) - 1) as u8;
+        let byte = &mut bytes[d / 8];
+        *byte = *byte & !(mask << (d % 8)) | (bits & mask) << (d % 8);
+        copied += n;
+    }
+
+    dst.replace_bytes(&bytes)
+}
+
+/// A byte-wise operation for `apply_mask`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaskOp {
+    And,
+    Or,
+    Xor,
+}
+
+/// Combines each byte of `bitfield` with the corresponding byte of `mask` using `op`.
+///
+/// Bits of `mask` at positions `>= bitfield.len()` are ignored, so that the excess bits of
+/// `bitfield` stay cleared. Returns `Error::InvalidByteCount` if `mask` is not the same length as
+/// `bitfield.as_slice()`, in which case `bitfield` is unchanged.
+pub fn apply_mask<B: AnyBitfield>(bitfield: &mut B, mask: &[u8], op: MaskOp) -> Result<(), Error> {
+    let bytes = bitfield.bytes();
+    if mask.len() != bytes.len() {
+        return Err(Error::InvalidByteCount {
+            given: mask.len(),
+            expected: bytes.len(),
+        });
+    }
+
+    let mut bytes: Vec<u8> = bytes
+        .iter()
+        .zip(mask)
+        .map(|(byte, mask)| match op {
+            MaskOp::And => byte & mask,
+            MaskOp::Or => byte | mask,
+            MaskOp::Xor => byte ^ mask,
+        })
+        .collect();
+    clear_excess_bits(&mut bytes, bitfield.bit_len());
+
+    bitfield.replace_bytes(&bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::BitList;
+    use ssz::Encode;
+    use tree_hash::merkle_root;
+    use typenum::{U12, U13, U16, U300, U32, U4, U5, U512, U8};
+
+    fn bitlist(len: usize, set: impl IntoIterator<Item = usize>) -> BitList<U16> {
+        let mut bitfield = BitList::with_capacity(len).unwrap();
+        for i in set {
+            bitfield.set(i, true).unwrap();
+        }
+        bitfield
+    }
+
+    #[test]
+    fn is_all_set_aligned() {
+        let mut bitvector = BitVector::<U8>::new();
+        assert!(!is_all_set(&bitvector));
+        for i in 0..8 {
+            bitvector.set(i, true).unwrap();
+        }
+        assert!(is_all_set(&bitvector));
+        bitvector.set(7, false).unwrap();
+        assert!(!is_all_set(&bitvector));
+
+        assert!(is_all_set(&bitlist(16, 0..16)));
+        assert!(!is_all_set(&bitlist(16, 1..16)));
+    }
+
+    #[test]
+    fn is_all_set_unaligned() {
+        let mut bitvector = BitVector::<U5>::new();
+        for i in 0..5 {
+            bitvector.set(i, true).unwrap();
+        }
+        assert!(is_all_set(&bitvector));
+        bitvector.set(4, false).unwrap();
+        assert!(!is_all_set(&bitvector));
+
+        for len in 0..16 {
+            assert!(is_all_set(&bitlist(len, 0..len)), "{}", len);
+            if len > 0 {
+                assert!(!is_all_set(&bitlist(len, 0..len - 1)), "{}", len);
+            }
+        }
+    }
+
+    #[test]
+    fn hamming_distance_equal_lengths() {
+        let mut a = BitVector::<U5>::new();
+        let mut b = BitVector::<U5>::new();
+        assert_eq!(hamming_distance(&a, &b), 0);
+        a.set(0, true).unwrap();
+        a.set(4, true).unwrap();
+        b.set(4, true).unwrap();
+        b.set(2, true).unwrap();
+        assert_eq!(hamming_distance(&a, &b), 2);
+        assert_eq!(hamming_distance(&b, &a), 2);
+
+        assert_eq!(
+            hamming_distance(&bitlist(16, 0..16), &bitlist(16, 8..16)),
+            8
+        );
+    }
+
+    #[test]
+    fn hamming_distance_different_lengths() {
+        assert_eq!(
+            hamming_distance(&bitlist(3, [0]), &bitlist(12, [0, 9, 11])),
+            2
+        );
+        assert_eq!(
+            hamming_distance(&bitlist(12, [0, 9, 11]), &bitlist(3, [1])),
+            4
+        );
+        assert_eq!(hamming_distance(&bitlist(0, []), &bitlist(9, 0..9)), 9);
+    }
+
+    #[test]
+    fn union_count_matches_union() {
+        let a = bitlist(12, [0, 3, 9]);
+        let b = bitlist(12, [3, 4, 11]);
+        assert_eq!(union_count(&a, &b), 5);
+        assert_eq!(union_count(&a, &b), a.union(&b).num_set_bits());
+
+        let mut x = BitVector::<U5>::new();
+        let mut y = BitVector::<U5>::new();
+        x.set(1, true).unwrap();
+        y.set(1, true).unwrap();
+        y.set(4, true).unwrap();
+        assert_eq!(union_count(&x, &y), 2);
+    }
+
+    #[test]
+    fn union_count_different_lengths() {
+        let short = bitlist(3, [0, 2]);
+        let long = bitlist(14, [2, 8, 13]);
+        assert_eq!(union_count(&short, &long), 4);
+        assert_eq!(union_count(&long, &short), 4);
+        assert_eq!(
+            union_count(&short, &long),
+            short.union(&long).num_set_bits()
+        );
+    }
+
+    #[test]
+    fn newly_set_indices() {
+        let prev = bitlist(16, [0, 3, 9, 15]);
+        let new = bitlist(16, [0, 1, 3, 7, 8, 9, 14]);
+        assert_eq!(
+            newly_set(&new, &prev).collect::<Vec<_>>(),
+            vec![1, 7, 8, 14]
+        );
+        assert_eq!(newly_set(&prev, &new).collect::<Vec<_>>(), vec![15]);
+        assert_eq!(newly_set(&new, &new).count(), 0);
+
+        let mut a = BitVector::<U5>::new();
+        let b = BitVector::<U5>::new();
+        a.set(4, true).unwrap();
+        assert_eq!(newly_set(&a, &b).collect::<Vec<_>>(), vec![4]);
+    }
+
+    #[test]
+    fn newly_set_different_lengths() {
+        let prev = bitlist(3, [0, 2]);
+        let new = bitlist(12, [0, 1, 2, 11]);
+        assert_eq!(newly_set(&new, &prev).collect::<Vec<_>>(), vec![1, 11]);
+        assert_eq!(newly_set(&prev, &new).count(), 0);
+    }
+
+    #[test]
+    fn copy_bits_from_misaligned() {
+        let src = bitlist(16, (0..16).filter(|i| i % 3 != 1));
+        for dst_start in 0..8 {
+            for src_start in 0..8 {
+                for len in 0..=8 {
+                    let mut dst = bitlist(16, [0, 5, 10, 15]);
+                    let mut expected = to_bool_vec(&dst);
+                    expected[dst_start..dst_start + len]
+                        .copy_from_slice(&to_bool_vec(&src)[src_start..src_start + len]);
+
+                    copy_bits_from(&mut dst, dst_start, &src, src_start, len).unwrap();
+                    assert_eq!(
+                        to_bool_vec(&dst),
+                        expected,
+                        "{} {} {}",
+                        dst_start,
+                        src_start,
+                        len
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn copy_bits_from_bitvector() {
+        let mut src = BitVector::<U16>::new();
+        for i in [6, 7, 8, 9] {
+            src.set(i, true).unwrap();
+        }
+        let mut dst = BitVector::<U16>::new();
+        copy_bits_from(&mut dst, 1, &src, 6, 4).unwrap();
+        assert_eq!(
+            dst.iter().positions(|bit| bit).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn copy_bits_from_out_of_bounds() {
+        let src = bitlist(8, 0..8);
+        let mut dst = bitlist(12, []);
+        assert_eq!(
+            copy_bits_from(&mut dst, 6, &src, 0, 7),
+            Err(Error::OutOfBounds { i: 13, len: 12 })
+        );
+        assert_eq!(
+            copy_bits_from(&mut dst, 0, &src, 4, 5),
+            Err(Error::OutOfBounds { i: 9, len: 8 })
+        );
+        assert_eq!(
+            copy_bits_from(&mut dst, usize::MAX, &src, 0, 1),
+            Err(Error::OutOfBounds {
+                i: usize::MAX,
+                len: 12
+            })
+        );
+        assert_eq!(dst, bitlist(12, []));
+        assert_eq!(copy_bits_from(&mut dst, 12, &src, 8, 0), Ok(()));
+    }
+
+    #[test]
+    fn apply_mask_ops() {
+        let mask = [0b1111_0000, 0b1111_1111];
+
+        let mut bitfield = bitlist(12, [0, 4, 8]);
+        apply_mask(&mut bitfield, &mask, MaskOp::And).unwrap();
+        assert_eq!(bitfield, bitlist(12, [4, 8]));
+
+        let mut bitfield = bitlist(12, [0, 4, 8]);
+        apply_mask(&mut bitfield, &mask, MaskOp::Or).unwrap();
+        assert_eq!(bitfield, bitlist(12, [0, 4, 5, 6, 7, 8, 9, 10, 11]));
+
+        let mut bitfield = bitlist(12, [0, 4, 8]);
+        apply_mask(&mut bitfield, &mask, MaskOp::Xor).unwrap();
+        assert_eq!(bitfield, bitlist(12, [0, 5, 6, 7, 9, 10, 11]));
+
+        let mut bitvector = BitVector::<U5>::new();
+        apply_mask(&mut bitvector, &[u8::MAX], MaskOp::Or).unwrap();
+        assert!(is_all_set(&bitvector));
+        assert_eq!(bitvector.as_slice(), &[0b0001_1111]);
+        apply_mask(&mut bitvector, &[0b0000_0101], MaskOp::Xor).unwrap();
+        assert_eq!(bitvector.as_slice(), &[0b0001_1010]);
+    }
+
+    #[test]
+    fn apply_mask_wrong_length() {
+        let mut bitfield = bitlist(12, [0]);
+        assert_eq!(
+            apply_mask(&mut bitfield, &[u8::MAX], MaskOp::Or),
+            Err(Error::InvalidByteCount {
+                given: 1,
+                expected: 2
+            })
+        );
+        assert_eq!(
+            apply_mask(&mut bitfield, &[u8::MAX; 3], MaskOp::Or),
+            Err(Error::InvalidByteCount {
+                given: 3,
+                expected: 2
+            })
+        );
+        assert_eq!(bitfield, bitlist(12, [0]));
+
+        let mut empty = bitlist(0, []);
+        apply_mask(&mut empty, &[u8::MAX], MaskOp::Or).unwrap();
+        assert_eq!(empty, bitlist(0, []));
+    }
+
+    #[test]
+    fn bool_vec_round_trip() {
+        let bits = vec![
+            true, false, true, true, false, false, false, false, true, false,
+        ];
+        let bitfield: BitList<U16> = try_from_bool_slice(&bits).unwrap();
+        assert_eq!(bitfield, bitlist(10, [0, 2, 3, 8]));
+        assert_eq!(to_bool_vec(&bitfield), bits);
+
+        let bitvector: BitVector<U5> =
+            try_from_bool_slice(&[false, true, false, false, true]).unwrap();
+        assert_eq!(
+            to_bool_vec(&bitvector),
+            vec![false, true, false, false, true]
+        );
+
+        let empty: BitList<U16> = try_from_bool_slice(&[]).unwrap();
+        assert_eq!(to_bool_vec(&empty), Vec::<bool>::new());
+    }
+
+    #[test]
+    fn try_from_bool_slice_wrong_length() {
+        assert_eq!(
+            try_from_bool_slice::<BitList<U4>>(&[true; 5]),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+        assert_eq!(
+            try_from_bool_slice::<BitVector<U5>>(&[true; 4]),
+            Err(Error::OutOfBounds { i: 4, len: 5 })
+        );
+        assert_eq!(
+            try_from_bool_slice::<BitVector<U5>>(&[true; 6]),
+            Err(Error::OutOfBounds { i: 6, len: 5 })
+        );
+    }
+
+    #[test]
+    fn union_many_matches_pairwise_union() {
+        let bitfields = [bitlist(12, [0, 3]), bitlist(12, [3, 9]), bitlist(12, [11])];
+        let union: BitList<U16> = union_many(12, &bitfields).unwrap();
+        assert_eq!(union, bitlist(12, [0, 3, 9, 11]));
+        assert_eq!(
+            union,
+            bitfields[0].union(&bitfields[1]).union(&bitfields[2])
+        );
+
+        let empty: BitList<U16> = union_many(12, []).unwrap();
+        assert_eq!(empty, bitlist(12, []));
+
+        let mut x = BitVector::<U5>::new();
+        let mut y = BitVector::<U5>::new();
+        x.set(0, true).unwrap();
+        y.set(4, true).unwrap();
+        assert_eq!(union_many(5, [&x, &y]), Ok(x.union(&y)));
+    }
+
+    #[test]
+    fn union_many_length_mismatch() {
+        let bitfields = [bitlist(12, [0]), bitlist(13, [1])];
+        assert_eq!(
+            union_many::<BitList<U16>, _>(12, &bitfields),
+            Err(Error::OutOfBounds { i: 13, len: 12 })
+        );
+        assert_eq!(
+            union_many::<BitList<U16>, _>(17, []),
+            Err(Error::OutOfBounds { i: 17, len: 16 })
+        );
+    }
+
+    #[test]
+    fn new_full_lengths() {
+        for len in 0..=16 {
+            let full: BitList<U16> = new_full(len).unwrap();
+            assert_eq!(full, bitlist(len, 0..len), "{}", len);
+            assert!(is_all_set(&full));
+        }
+
+        let empty: BitList<U16> = new_full(0).unwrap();
+        assert_eq!(empty.as_slice(), &[0]);
+
+        let partial: BitList<U16> = new_full(10).unwrap();
+        assert_eq!(partial.as_slice(), &[u8::MAX, 0b0000_0011]);
+
+        let bitvector: BitVector<U5> = new_full(5).unwrap();
+        assert_eq!(bitvector.as_slice(), &[0b0001_1111]);
+        assert_eq!(bitvector.num_set_bits(), 5);
+    }
+
+    #[test]
+    fn new_full_invalid_length() {
+        assert_eq!(
+            new_full::<BitList<U16>>(17),
+            Err(Error::OutOfBounds { i: 17, len: 16 })
+        );
+        assert_eq!(
+            new_full::<BitVector<U5>>(4),
+            Err(Error::OutOfBounds { i: 4, len: 5 })
+        );
+    }
+
+    #[test]
+    fn byte_array_round_trip() {
+        let mut bitvector = BitVector::<U16>::new();
+        for i in [0, 3, 15] {
+            bitvector.set(i, true).unwrap();
+        }
+        let array: [u8; 2] = to_byte_array(&bitvector).unwrap();
+        assert_eq!(array, [0b0000_1001, 0b1000_0000]);
+        assert_eq!(array.to_vec(), bitvector.as_ssz_bytes());
+        assert_eq!(from_byte_array::<U16, 2>(&array), Ok(bitvector));
+
+        let bitvector = BitVector::<U12>::new();
+        assert_eq!(to_byte_array::<U12, 2>(&bitvector), Ok([0, 0]));
+    }
+
+    #[test]
+    fn byte_array_wrong_size() {
+        let bitvector = BitVector::<U16>::new();
+        assert_eq!(
+            to_byte_array::<U16, 1>(&bitvector),
+            Err(Error::InvalidByteCount {
+                given: 1,
+                expected: 2
+            })
+        );
+        assert_eq!(
+            to_byte_array::<U16, 3>(&bitvector),
+            Err(Error::InvalidByteCount {
+                given: 3,
+                expected: 2
+            })
+        );
+        assert_eq!(
+            from_byte_array::<U16, 3>(&[0; 3]),
+            Err(Error::InvalidByteCount {
+                given: 3,
+                expected: 2
+            })
+        );
+        assert_eq!(
+            from_byte_array::<U12, 2>(&[0, 0b0001_0000]),
+            Err(Error::ExcessBits)
+        );
+    }
+
+    #[test]
+    fn builder_out_of_order_and_duplicates() {
+        let mut builder = BitListBuilder::<U16>::new();
+        for i in [9, 2, 9, 0, 2, 11] {
+            builder.set(i).unwrap();
+        }
+        assert_eq!(builder.clone().finish(12), Ok(bitlist(12, [0, 2, 9, 11])));
+        assert_eq!(builder.finish(16), Ok(bitlist(16, [0, 2, 9, 11])));
+
+        assert_eq!(
+            BitListBuilder::<U16>::default().finish(0),
+            Ok(bitlist(0, []))
+        );
+    }
+
+    #[test]
+    fn builder_out_of_range() {
+        let mut builder = BitListBuilder::<U16>::new();
+        builder.set(3).unwrap();
+        builder.set(10).unwrap();
+        assert_eq!(
+            builder.clone().finish(10),
+            Err(Error::OutOfBounds { i: 10, len: 10 })
+        );
+        assert_eq!(
+            builder.finish(17),
+            Err(Error::OutOfBounds { i: 17, len: 16 })
+        );
+
+        let mut builder = BitListBuilder::<U16>::new();
+        assert_eq!(builder.set(16), Err(Error::OutOfBounds { i: 16, len: 16 }));
+    }
+
+    fn chunks_root(chunks: &[Hash256], leaf_count: usize) -> Hash256 {
+        merkle_root(&chunks.concat(), leaf_count)
+    }
+
+    #[test]
+    fn tree_hash_chunks_bitvector() {
+        let mut bitvector = BitVector::<U512>::new();
+        for i in [0, 255, 256, 511] {
+            bitvector.set(i, true).unwrap();
+        }
+        let chunks = tree_hash_chunks(&bitvector);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks_root(&chunks, 2), bitvector.tree_hash_root());
+
+        let bitvector = new_full::<BitVector<U5>>(5).unwrap();
+        let chunks = tree_hash_chunks(&bitvector);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0][0], 0b0001_1111);
+        assert_eq!(chunks_root(&chunks, 1), bitvector.tree_hash_root());
+    }
+
+    #[test]
+    fn tree_hash_chunks_bitlist() {
+        // `U300` has capacity for two chunks of bits.
+        for len in [0, 7, 8, 256, 257, 300] {
+            let bitlist: BitList<U300> = new_full(len).unwrap();
+            let chunks = tree_hash_chunks(&bitlist);
+            let (length, data) = chunks.split_last().unwrap();
+
+            assert_eq!(data.len(), std::cmp::max(1, len.div_ceil(8).div_ceil(32)));
+            assert_eq!(&length[..8], &(len as u64).to_le_bytes());
+            assert_eq!(
+                chunks_root(&[chunks_root(data, 2), *length], 2),
+                bitlist.tree_hash_root(),
+                "{}",
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn truncate_clears_bits_past_new_len() {
+        let mut bitfield = bitlist(16, [0, 5, 7, 8, 9, 15]);
+        truncate(&mut bitfield, 9);
+        assert_eq!(bitfield, bitlist(9, [0, 5, 7, 8]));
+        assert_eq!(bitfield.as_slice(), &[0b1010_0001, 0b0000_0001]);
+
+        // Across a byte boundary, dropping the second byte entirely.
+        truncate(&mut bitfield, 6);
+        assert_eq!(bitfield, bitlist(6, [0, 5]));
+        assert_eq!(bitfield.as_slice(), &[0b0010_0001]);
+
+        truncate(&mut bitfield, 0);
+        assert_eq!(bitfield, bitlist(0, []));
+    }
+
+    #[test]
+    fn truncate_longer_is_noop() {
+        let mut bitfield = bitlist(10, [1, 9]);
+        truncate(&mut bitfield, 10);
+        assert_eq!(bitfield, bitlist(10, [1, 9]));
+        truncate(&mut bitfield, 16);
+        assert_eq!(bitfield, bitlist(10, [1, 9]));
+    }
+
+    #[test]
+    fn iter_zeros_in_range_only() {
+        let bitfield: BitList<U32> = try_from_bool_slice(&[true; 10]).unwrap();
+        assert_eq!(iter_zeros(&bitfield).count(), 0);
+
+        let bitfield: BitList<U32> = from_set_indices(10, [0, 2, 3, 4, 5, 6, 7, 9]).unwrap();
+        assert_eq!(iter_zeros(&bitfield).collect::<Vec<_>>(), vec![1, 8]);
+
+        let bitfield: BitList<U32> = from_set_indices(17, (0..16).filter(|i| i % 2 == 0)).unwrap();
+        assert_eq!(
+            iter_zeros(&bitfield).collect::<Vec<_>>(),
+            vec![1, 3, 5, 7, 9, 11, 13, 15, 16]
+        );
+
+        let empty: BitList<U32> = BitList::with_capacity(0).unwrap();
+        assert_eq!(iter_zeros(&empty).count(), 0);
+
+        let mut bitvector = BitVector::<U5>::new();
+        bitvector.set(1, true).unwrap();
+        assert_eq!(iter_zeros(&bitvector).collect::<Vec<_>>(), vec![0, 2, 3, 4]);
+    }
+
+    fn assert_reversed<B: AnyBitfield + std::fmt::Debug>(bitfield: B) {
+        let mut reversed = bitfield.clone();
+        reverse_bits(&mut reversed);
+
+        let (before, after) = (to_bool_vec(&bitfield), to_bool_vec(&reversed));
+        let len = before.len();
+        for i in 0..len {
+            assert_eq!(before[i], after[len - 1 - i], "{:?} {}", bitfield, i);
+        }
+
+        reverse_bits(&mut reversed);
+        assert_eq!(to_bool_vec(&reversed), before);
+    }
+
+    #[test]
+    fn reverse_bits_aligned() {
+        let bitvector: BitVector<U16> = from_set_indices(16, [0, 1, 7, 10]).unwrap();
+        let mut reversed = bitvector.clone();
+        reverse_bits(&mut reversed);
+        assert_eq!(reversed, from_set_indices(16, [15, 14, 8, 5]).unwrap());
+        assert_reversed(bitvector);
+
+        assert_reversed(bitlist(16, [0, 3, 8, 15]));
+    }
+
+    #[test]
+    fn reverse_bits_unaligned() {
+        let bitvector: BitVector<U13> = from_set_indices(13, [0, 1, 9]).unwrap();
+        let mut reversed = bitvector.clone();
+        reverse_bits(&mut reversed);
+        assert_eq!(reversed, from_set_indices(13, [12, 11, 3]).unwrap());
+        assert_reversed(bitvector);
+
+        for len in 0..16 {
+            assert_reversed(bitlist(len, (0..len).filter(|i| i % 3 == 0)));
+        }
+    }
+
+    #[test]
+    fn cmp_by_weight_ignores_positions() {
+        assert_eq!(
+            cmp_by_weight(&bitlist(12, [0, 1]), &bitlist(12, [11])),
+            Ordering::Greater
+        );
+        assert_eq!(
+            cmp_by_weight(&bitlist(12, [0]), &bitlist(12, [11])),
+            Ordering::Equal
+        );
+        assert_eq!(
+            cmp_by_weight(&bitlist(3, [0]), &bitlist(12, [8, 11])),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn max_by_weight_first_of_ties() {
+        let bitfields = [
+            bitlist(12, [0]),
+            bitlist(12, [1, 2]),
+            bitlist(12, [10, 11]),
+            bitlist(12, [3]),
+        ];
+        let max = max_by_weight(&bitfields).unwrap();
+        assert!(std::ptr::eq(max, &bitfields[1]));
+
+        let max = max_by_weight(bitfields.iter().rev()).unwrap();
+        assert!(std::ptr::eq(max, &bitfields[2]));
+
+        assert_eq!(max_by_weight::<BitList<U16>, _>([]), None);
+    }
+
+    #[test]
+    fn widen_and_narrow() {
+        let bitvector: BitVector<U8> = from_set_indices(8, [0, 3, 7]).unwrap();
+        let widened: BitVector<U16> = widen(&bitvector);
+        assert_eq!(widened, from_set_indices(16, [0, 3, 7]).unwrap());
+        assert_eq!(narrow::<U16, U8>(&widened), Ok(bitvector.clone()));
+        assert_eq!(widen::<U8, U8>(&bitvector), bitvector);
+
+        let bitvector: BitVector<U5> = new_full(5).unwrap();
+        let widened: BitVector<U13> = widen(&bitvector);
+        assert_eq!(widened.as_slice(), &[0b0001_1111, 0]);
+        assert_eq!(narrow::<U13, U5>(&widened), Ok(bitvector));
+    }
+
+    #[test]
+    fn narrow_set_bit_dropped() {
+        let bitvector: BitVector<U16> = from_set_indices(16, [1, 8, 12]).unwrap();
+        assert_eq!(
+            narrow::<U16, U8>(&bitvector),
+            Err(Error::OutOfBounds { i: 12, len: 8 })
+        );
+        assert_eq!(
+            narrow::<U16, U12>(&bitvector),
+            Err(Error::OutOfBounds { i: 12, len: 12 })
+        );
+        assert_eq!(
+            narrow::<U16, U13>(&bitvector),
+            Ok(from_set_indices(13, [1, 8, 12]).unwrap())
+        );
+    }
+
+    #[test]
+    fn compact_indices_round_trip() {
+        for len in [0, 1, 9, 16] {
+            let bitfield = bitlist(len, (0..len).filter(|i| i % 4 == 1));
+            let (compact_len, indices) = to_compact_indices(&bitfield);
+            assert_eq!(compact_len, len);
+            assert_eq!(from_compact_indices(compact_len, &indices), Ok(bitfield));
+        }
+
+        let bitvector: BitVector<U13> = from_set_indices(13, [2, 12]).unwrap();
+        assert_eq!(to_compact_indices(&bitvector), (13, vec![2, 12]));
+        assert_eq!(from_compact_indices(13, &[2, 12]), Ok(bitvector));
+    }
+
+    #[test]
+    fn from_compact_indices_invalid() {
+        assert_eq!(
+            from_compact_indices::<BitList<U16>>(10, &[3, 10]),
+            Err(Error::OutOfBounds { i: 10, len: 10 })
+        );
+        assert_eq!(
+            from_compact_indices::<BitList<U16>>(17, &[]),
+            Err(Error::OutOfBounds { i: 17, len: 16 })
+        );
+        assert_eq!(
+            from_compact_indices::<BitVector<U13>>(12, &[]),
+            Err(Error::OutOfBounds { i: 12, len: 13 })
+        );
+    }
+
+    #[test]
+    fn complement_unaligned() {
+        for len in 0..16 {
+            let bitfield = bitlist(len, (0..len).filter(|i| i % 3 == 0));
+            let complement = complement(&bitfield);
+            assert_eq!(complement.len(), len);
+            assert_eq!(
+                complement.num_set_bits(),
+                len - bitfield.num_set_bits(),
+                "{}",
+                len
+            );
+            assert!(complement.intersection(&bitfield).is_zero());
+            assert!(is_all_set(&complement.union(&bitfield)));
+        }
+
+        let bitvector: BitVector<U13> = from_set_indices(13, [0, 12]).unwrap();
+        let complement = complement(&bitvector);
+        assert_eq!(complement.as_slice(), &[0b1111_1110, 0b0000_1111]);
+        assert_eq!(complement.num_set_bits(), 11);
+    }
+}
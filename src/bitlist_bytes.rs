@@ -0,0 +1,200 @@
+//! Inspect SSZ-encoded `BitList` bytes without decoding them.
+//!
+//! These functions allow cheaply reading the length or a single bit of an untrusted `BitList`
+//! (e.g., during gossip validation) before committing to a full decode. They accept and reject
+//! exactly the same bytes as `<BitList<N> as ssz::Decode>::from_ssz_bytes`.
+//!
+//! `from_raw_bytes_lenient` builds a `BitList` from raw bytes which may be over-padded.
+use crate::{BitList, Error};
+use smallvec::SmallVec;
+use typenum::Unsigned;
+
+/// Returns the length of the `BitList<N>` encoded in `bytes`, as indicated by the highest set
+/// (delimiter) bit.
+///
+/// Returns `Err` if `bytes` is not a valid SSZ encoding of a `BitList<N>`.
+pub fn decode_len<N: Unsigned>(bytes: &[u8]) -> Result<usize, Error> {
+    let last_byte = *bytes.last().ok_or(Error::ExcessBits)?;
+
+    if last_byte == 0 {
+        // The delimiter bit must be in the last byte.
+        return match bytes.iter().rposition(|&byte| byte != 0) {
+            Some(i) => Err(Error::InvalidByteCount {
+                given: bytes.len(),
+                expected: i + 1,
+            }),
+            None => Err(Error::MissingLengthInformation),
+        };
+    }
+
+    let len = (bytes.len() - 1) * 8 + (7 - last_byte.leading_zeros() as usize);
+
+    if len > N::to_usize() {
+        Err(Error::OutOfBounds {
+            i: len,
+            len: N::to_usize(),
+        })
+    } else {
+        Ok(len)
+    }
+}
+
+/// Returns `true` if bit `i` of the `BitList<N>` encoded in `bytes` is set.
+///
+/// Returns `false` if `i` is not less than the length of the list (including the delimiter bit)
+/// or if `bytes` is not a valid SSZ encoding of a `BitList<N>`.
+pub fn bit_at<N: Unsigned>(bytes: &[u8], i: usize) -> bool {
+    decode_len::<N>(bytes).is_ok_and(|len| i < len && bytes[i / 8] & (1 << (i % 8)) != 0)
+}
+
+/// Builds a `BitList<N>` of length `len` from raw `bytes` (i.e., as per `Bitfield::as_slice`,
+/// without the length delimiter bit), tolerating trailing padding.
+///
+/// Any number of all-zero bytes after the minimal `max(1, ceil(len / 8))` bytes are ignored. A
+/// set bit at any position `>= len`, whether in the minimal bytes or in the padding, is still
+/// rejected with `Error::ExcessBits`.
+pub fn from_raw_bytes_lenient<N: Unsigned + Clone>(
+    bytes: &[u8],
+    len: usize,
+) -> Result<BitList<N>, Error> {
+    if len > N::to_usize() {
+        return Err(Error::OutOfBounds {
+            i: len,
+            len: N::to_usize(),
+        });
+    }
+
+    let minimal = std::cmp::max(1, len.div_ceil(8));
+    if bytes.len() < minimal {
+        return Err(Error::InvalidByteCount {
+            given: bytes.len(),
+            expected: minimal,
+        });
+    }
+
+    let (bytes, padding) = bytes.split_at(minimal);
+    let bits_in_last_byte = (len - (minimal - 1) * 8) as u32;
+    if padding.iter().any(|&byte| byte != 0)
+        || bytes[minimal - 1]
+            .checked_shr(bits_in_last_byte)
+            .is_some_and(|excess| excess != 0)
+    {
+        return Err(Error::ExcessBits);
+    }
+
+    // Re-encode with the length delimiter bit set at position `len`.
+    let mut encoded = SmallVec::from_slice(bytes);
+    if len / 8 == encoded.len() {
+        encoded.push(1);
+    } else {
+        encoded[len / 8] |= 1 << (len % 8);
+    }
+
+    Ok(BitList::from_bytes(encoded).expect("bytes are a valid BitList encoding"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::BitList;
+    use ssz::{Decode, Encode};
+    use typenum::U16;
+
+    type List = BitList<U16>;
+
+    #[test]
+    fn matches_decoded_bitlist() {
+        for len in 0..=16 {
+            let mut list = List::with_capacity(len).unwrap();
+            for i in (0..len).filter(|i| i % 3 == 0 || i % 5 == 0) {
+                list.set(i, true).unwrap();
+            }
+            let bytes = list.as_ssz_bytes();
+
+            assert_eq!(decode_len::<U16>(&bytes), Ok(len));
+            for i in 0..24 {
+                assert_eq!(bit_at::<U16>(&bytes, i), list.get(i).unwrap_or(false));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_bytes() {
+        let invalid: &[&[u8]] = &[&[], &[0], &[0, 0], &[1, 0]];
+
+        for bytes in invalid {
+            let expected = List::from_ssz_bytes(bytes).unwrap_err();
+            let err = decode_len::<U16>(bytes).unwrap_err();
+            assert!(
+                format!("{:?}", expected).contains(&format!("{:?}", err)),
+                "{:?} {:?}",
+                expected,
+                err
+            );
+            assert!(!bit_at::<U16>(bytes, 0));
+        }
+    }
+
+    #[test]
+    fn from_raw_bytes_lenient_matches_bitlist() {
+        for len in 0..=16 {
+            let mut list = List::with_capacity(len).unwrap();
+            for i in (0..len).filter(|i| i % 3 == 0) {
+                list.set(i, true).unwrap();
+            }
+
+            let mut raw = list.as_slice().to_vec();
+            assert_eq!(from_raw_bytes_lenient::<U16>(&raw, len), Ok(list.clone()));
+
+            // Trailing all-zero padding is tolerated.
+            raw.extend_from_slice(&[0; 5]);
+            assert_eq!(from_raw_bytes_lenient::<U16>(&raw, len), Ok(list));
+        }
+    }
+
+    #[test]
+    fn from_raw_bytes_lenient_rejects_excess_bits() {
+        // Set bit just beyond `len` in the minimal bytes.
+        assert_eq!(
+            from_raw_bytes_lenient::<U16>(&[0b0000_1000, 0, 0], 3),
+            Err(Error::ExcessBits)
+        );
+        // Set bit in the padding.
+        assert_eq!(
+            from_raw_bytes_lenient::<U16>(&[0b0000_0111, 0, 1], 3),
+            Err(Error::ExcessBits)
+        );
+        assert_eq!(
+            from_raw_bytes_lenient::<U16>(&[1], 0),
+            Err(Error::ExcessBits)
+        );
+        assert_eq!(
+            from_raw_bytes_lenient::<U16>(&[0xff], 9),
+            Err(Error::InvalidByteCount {
+                given: 1,
+                expected: 2
+            })
+        );
+        assert_eq!(
+            from_raw_bytes_lenient::<U16>(&[0, 0, 0], 17),
+            Err(Error::OutOfBounds { i: 17, len: 16 })
+        );
+    }
+
+    #[test]
+    fn rejects_too_long() {
+        let too_long: &[(&[u8], usize)] = &[
+            (&[0b0000_0001, 0b0000_0001, 0b0000_0010], 17),
+            (&[0xff, 0xff, 0xff], 23),
+        ];
+
+        for (bytes, len) in too_long {
+            assert!(List::from_ssz_bytes(bytes).is_err());
+            assert_eq!(
+                decode_len::<U16>(bytes),
+                Err(Error::OutOfBounds { i: *len, len: 16 })
+            );
+            assert!(!bit_at::<U16>(bytes, 0));
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Convert a `BitVector` to and from bytes with most-significant-bit-first bit order.
+//!
+//! ## Warning
+//!
+//! SSZ numbers the bits within each byte least-significant-first: bit `i` of a `BitVector` is
+//! `bytes[i / 8] & (1 << (i % 8))`. These functions instead number bits most-significant-first,
+//! i.e., bit `i` is `bytes[i / 8] & (0x80 >> (i % 8))`, for interoperating with systems that use
+//! that convention.
+//!
+//! The bytes produced and consumed here are **not** SSZ and must never be used for consensus
+//! data. Use `ssz::Encode` and `ssz::Decode` for that.
+use crate::{BitVector, Error};
+use typenum::Unsigned;
+
+/// Returns the bits of `bitvector` as bytes, numbering the bits within each byte
+/// most-significant-first.
+///
+/// Bits beyond `N` in the final byte are zero.
+pub fn to_msb_first_bytes<N: Unsigned + Clone>(bitvector: &BitVector<N>) -> Vec<u8> {
+    bitvector
+        .as_slice()
+        .iter()
+        .map(|byte| byte.reverse_bits())
+        .collect()
+}
+
+/// Builds a `BitVector<N>` from `bytes` whose bits are numbered most-significant-first within
+/// each byte, as per `to_msb_first_bytes`.
+///
+/// Returns `Err` if `bytes` is not exactly `ceil(N / 8)` bytes long or if any bit at a position
+/// `>= N` is set.
+pub fn from_msb_first_bytes<N: Unsigned + Clone>(bytes: &[u8]) -> Result<BitVector<N>, Error> {
+    BitVector::from_bytes(bytes.iter().map(|byte| byte.reverse_bits()).collect())
+        .map_err(Error::from)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ssz::Encode;
+    use typenum::{U12, U16};
+
+    #[test]
+    fn msb_first_bit_order() {
+        let mut bitvector = BitVector::<U12>::new();
+        for i in [0, 3, 11] {
+            bitvector.set(i, true).unwrap();
+        }
+
+        assert_eq!(bitvector.as_ssz_bytes(), vec![0b0000_1001, 0b0000_1000]);
+        assert_eq!(
+            to_msb_first_bytes(&bitvector),
+            vec![0b1001_0000, 0b0001_0000]
+        );
+        assert_eq!(
+            from_msb_first_bytes::<U12>(&[0b1001_0000, 0b0001_0000]),
+            Ok(bitvector)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for len in 0..16 {
+            let mut bitvector = BitVector::<U16>::new();
+            for i in (0..len).filter(|i| i % 3 != 1) {
+                bitvector.set(i, true).unwrap();
+            }
+            let bytes = to_msb_first_bytes(&bitvector);
+            assert_eq!(from_msb_first_bytes::<U16>(&bytes), Ok(bitvector));
+        }
+    }
+
+    #[test]
+    fn invalid_bytes() {
+        // Bit 12 is beyond `N`.
+        assert_eq!(
+            from_msb_first_bytes::<U12>(&[0, 0b0000_1000]),
+            Err(Error::ExcessBits)
+        );
+        assert_eq!(
+            from_msb_first_bytes::<U12>(&[0]),
+            Err(Error::InvalidByteCount {
+                given: 1,
+                expected: 2
+            })
+        );
+        assert_eq!(
+            from_msb_first_bytes::<U12>(&[0, 0, 0]),
+            Err(Error::InvalidByteCount {
+                given: 3,
+                expected: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! SSZ decoding with a limit on the total number of elements decoded.
+//!
+//! Decoding deeply nested lists (e.g., `VariableList<VariableList<u8, M>, N>`) from untrusted
+//! bytes may allocate once per element at every layer. `BoundedDecode` threads a single element
+//! budget through all of the `VariableList` and `FixedVector` layers of a type so that one decode
+//! cannot allocate more than the caller allows.
+//!
+//! The checks made on a list's length before decoding any items are shared with the `Decode`
+//! implementations of `VariableList` and `FixedVector`.
+use ssz::{Decode, DecodeError, BYTES_PER_LENGTH_OFFSET};
+use tree_hash::Hash256;
+
+/// An SSZ `Decode` which charges each decoded list or vector element against a shared budget.
+///
+/// There is deliberately no default implementation: a type containing lists must pass `budget` on
+/// to them, otherwise they escape the limit.
+pub trait BoundedDecode: Decode {
+    /// Decodes `bytes`, subtracting the number of list and vector elements decoded from
+    /// `budget`. Returns an `Err` if `budget` would be exhausted.
+    fn from_ssz_bytes_with_budget(bytes: &[u8], budget: &mut usize) -> Result<Self, DecodeError>;
+}
+
+/// Implements `BoundedDecode` for types which contain no lists, and so don't touch the budget.
+macro_rules! impl_bounded_decode {
+    ($($type: ty),*) => {
+        $(
+            impl BoundedDecode for $type {
+                fn from_ssz_bytes_with_budget(
+                    bytes: &[u8],
+                    _budget: &mut usize,
+                ) -> Result<Self, DecodeError> {
+                    Self::from_ssz_bytes(bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_bounded_decode!(u8, u16, u32, u64, u128, bool, Hash256);
+
+/// Subtracts `num_items` from `budget`, returning an `Err` if there is insufficient budget.
+fn charge_budget(budget: &mut usize, num_items: usize) -> Result<(), DecodeError> {
+    *budget = budget.checked_sub(num_items).ok_or_else(|| {
+        DecodeError::BytesInvalid(format!(
+            "Decoding {} items exceeds remaining element budget of {}",
+            num_items, budget
+        ))
+    })?;
+    Ok(())
+}
+
+/// Returns an `Err` if a list of `num_items` items exceeds its maximum length of `max_len`.
+///
+/// `list_type` is the name of the list type being decoded, used in error messages.
+pub(crate) fn check_max_len(
+    num_items: usize,
+    max_len: usize,
+    list_type: &str,
+) -> Result<(), DecodeError> {
+    if num_items > max_len {
+        return Err(DecodeError::BytesInvalid(format!(
+            "{}: {} items exceeds maximum of {}",
+            list_type, num_items, max_len
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the number of items in `bytes`, the encoding of a list of fixed-length `T`.
+///
+/// Returns an `Err` if `bytes` ends with a partial item, so that nothing is allocated for it.
+pub(crate) fn fixed_len_item_count<T: Decode>(bytes: &[u8]) -> Result<usize, DecodeError> {
+    let num_items = bytes
+        .len()
+        .checked_div(T::ssz_fixed_len())
+        .ok_or(DecodeError::ZeroLengthItem)?;
+
+    if !bytes.len().is_multiple_of(T::ssz_fixed_len()) {
+        return Err(DecodeError::InvalidByteLength {
+            len: bytes.len(),
+            expected: num_items * T::ssz_fixed_len(),
+        });
+    }
+    Ok(num_items)
+}
+
+/// Returns the number of items in the non-empty `bytes`, the encoding of a list of
+/// variable-length items, as implied by its first offset.
+///
+/// The count is checked against `max_len` before the offset is otherwise validated, so that a
+/// crafted offset table can't trigger a large allocation.
+pub(crate) fn variable_len_item_count(
+    bytes: &[u8],
+    max_len: usize,
+    list_type: &str,
+) -> Result<usize, DecodeError> {
+    let num_items = ssz::read_offset(bytes)? / BYTES_PER_LENGTH_OFFSET;
+    check_max_len(num_items, max_len, list_type)?;
+    Ok(num_items)
+}
+
+/// Decodes `bytes` as a list of at most `max_len` items, charging every item against `budget`
+/// before any of them are decoded.
+///
+/// `list_type` is the name of the list type being decoded, used in error messages.
+pub(crate) fn decode_list_with_budget<T: BoundedDecode>(
+    bytes: &[u8],
+    max_len: usize,
+    budget: &mut usize,
+    list_type: &str,
+) -> Result<Vec<T>, DecodeError> {
+    if bytes.is_empty() {
+        return Ok(vec![]);
+    }
+
+    if T::is_ssz_fixed_len() {
+        let num_items = fixed_len_item_count::<T>(bytes)?;
+        check_max_len(num_items, max_len, list_type)?;
+        charge_budget(budget, num_items)?;
+
+        bytes
+            .chunks(T::ssz_fixed_len())
+            .map(|chunk| T::from_ssz_bytes_with_budget(chunk, budget))
+            .collect()
+    } else {
+        let num_items = variable_len_item_count(bytes, max_len, list_type)?;
+
+        let first_offset = ssz::read_offset(bytes)?;
+        if first_offset > bytes.len() {
+            return Err(DecodeError::OffsetOutOfBounds(first_offset));
+        }
+        if first_offset % BYTES_PER_LENGTH_OFFSET != 0 || first_offset < BYTES_PER_LENGTH_OFFSET {
+            return Err(DecodeError::InvalidListFixedBytesLen(first_offset));
+        }
+        charge_budget(budget, num_items)?;
+
+        let mut vec = Vec::with_capacity(num_items);
+        let mut start = first_offset;
+        for i in 1..=num_items {
+            let end = if i == num_items {
+                bytes.len()
+            } else {
+                ssz::read_offset(&bytes[i * BYTES_PER_LENGTH_OFFSET..])?
+            };
+
+            if end < start {
+                return Err(DecodeError::OffsetsAreDecreasing(end));
+            } else if end > bytes.len() {
+                return Err(DecodeError::OffsetOutOfBounds(end));
+            }
+
+            vec.push(T::from_ssz_bytes_with_budget(&bytes[start..end], budget)?);
+            start = end;
+        }
+        Ok(vec)
+    }
+}
@@ -1,9 +1,17 @@
-use crate::tree_hash::vec_tree_hash_root;
-use crate::Error;
-use serde_derive::{Deserialize, Serialize};
+use crate::bounded_decode::{decode_list_with_budget, fixed_len_item_count, BoundedDecode};
+use crate::tree_hash::{checked_vec_tree_hash_root, vec_tree_hash_root};
+use crate::{checked_range, decode_hex, list_type_name, Error, HexError};
+use serde::de::{Error as _, SeqAccess};
+use serde::{Deserialize, Deserializer};
+use serde_derive::Serialize;
+use smallvec::SmallVec;
+use std::any::type_name;
+use std::borrow::Cow;
+use std::iter::FusedIterator;
 use std::marker::PhantomData;
-use std::ops::{Deref, DerefMut, Index, IndexMut};
-use std::slice::SliceIndex;
+use std::ops::{Deref, DerefMut, Index, IndexMut, RangeBounds};
+use std::slice::{GetDisjointMutError, SliceIndex};
+use std::sync::Arc;
 use tree_hash::Hash256;
 use typenum::Unsigned;
 
@@ -21,8 +29,13 @@ pub use typenum;
 ///
 /// ## Note
 ///
-/// Whilst it is possible with this library, SSZ declares that a `FixedVector` with a length of `0`
-/// is illegal.
+/// SSZ declares that a `FixedVector` with a length of `0` is illegal. The fallible constructors
+/// (e.g., `new`, `try_from_iter` and SSZ/serde decoding) return `Error::EmptyFixedVector` when
+/// `N` is `0`. The infallible `From<Vec<T>>`, `Default` and `from_elem` still permit it, but the
+/// resulting value cannot be SSZ decoded.
+///
+/// A `FixedVector<bool, N>` is an SSZ `Vector[boolean, N]`, which encodes each `bool` as a whole
+/// `0x00` or `0x01` byte. It is _not_ bit-packed; use `BitVector` for an SSZ `Bitvector`.
 ///
 /// ## Example
 ///
@@ -44,13 +57,27 @@ pub use typenum;
 /// let long: FixedVector<_, typenum::U5> = FixedVector::from(base);
 /// assert_eq!(&long[..], &[1, 2, 3, 4, 0]);
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Serialize)]
 #[serde(transparent)]
 pub struct FixedVector<T, N> {
     vec: Vec<T>,
     _phantom: PhantomData<N>,
 }
 
+impl<T: Clone, N> Clone for FixedVector<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            vec: self.vec.clone(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Reuses the existing allocation of `self`, where possible.
+    fn clone_from(&mut self, source: &Self) {
+        self.vec.clone_from(&source.vec);
+    }
+}
+
 // Implement comparison functions even if N doesn't implement PartialEq
 impl<T: PartialEq, N> PartialEq for FixedVector<T, N> {
     fn eq(&self, other: &Self) -> bool {
@@ -58,17 +85,22 @@ impl<T: PartialEq, N> PartialEq for FixedVector<T, N> {
     }
 }
 impl<T: Eq, N> Eq for FixedVector<T, N> {}
+// Hash the contents only, so that lists which compare equal hash equally regardless of capacity.
 impl<T: std::hash::Hash, N> std::hash::Hash for FixedVector<T, N> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.vec.hash(state);
+        self.vec.as_slice().hash(state);
     }
 }
 
 impl<T, N: Unsigned> FixedVector<T, N> {
     /// Returns `Ok` if the given `vec` equals the fixed length of `Self`. Otherwise returns
     /// `Err`.
+    ///
+    /// Always returns `Err` if the fixed length of `Self` is `0`, which is illegal in SSZ.
     pub fn new(vec: Vec<T>) -> Result<Self, Error> {
-        if vec.len() == Self::capacity() {
+        if Self::capacity() == 0 {
+            Err(Error::EmptyFixedVector)
+        } else if vec.len() == Self::capacity() {
             Ok(Self {
                 vec,
                 _phantom: PhantomData,
@@ -81,6 +113,57 @@ impl<T, N: Unsigned> FixedVector<T, N> {
         }
     }
 
+    /// Returns `Ok` if the length of `data` equals the fixed length of `Self`. Otherwise returns
+    /// `Err`.
+    ///
+    /// An owned `data` is moved into `self` without copying. A borrowed `data` is only cloned
+    /// once its length has been checked.
+    pub fn from_cow(data: Cow<'_, [T]>) -> Result<Self, Error>
+    where
+        T: Clone,
+    {
+        if data.len() != Self::capacity() {
+            return Err(Error::OutOfBounds {
+                i: data.len(),
+                len: Self::capacity(),
+            });
+        }
+        Self::new(data.into_owned())
+    }
+
+    /// Builds a vector from an iterator which must yield exactly `N` items.
+    ///
+    /// Returns `Error::OutOfBounds { i, len: N }` otherwise, where `i` is the number of items
+    /// yielded. If the iterator yields too many items, iteration stops at the first excess item
+    /// and `i` is `N + 1`.
+    ///
+    /// Equivalent to `ssz::TryFromIter::try_from_iter`, without needing the trait in scope.
+    pub fn try_from_iter_exact<I>(iter: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        <Self as ssz::TryFromIter<T>>::try_from_iter(iter)
+    }
+
+    /// Builds a vector from an `ExactSizeIterator` whose `len` is `N`, without a per-item bound
+    /// check.
+    ///
+    /// Returns `Error::OutOfBounds { i: iter.len(), len: N }` before consuming `iter` if its length
+    /// is not `N`.
+    pub fn from_exact_iter<I>(iter: I) -> Result<Self, Error>
+    where
+        I: ExactSizeIterator<Item = T>,
+    {
+        if iter.len() != Self::capacity() {
+            return Err(Error::OutOfBounds {
+                i: iter.len(),
+                len: Self::capacity(),
+            });
+        }
+        // `new` still rejects an iterator which misreports its length.
+        Self::new(iter.collect())
+    }
+
     /// Create a new vector filled with clones of `elem`.
     pub fn from_elem(elem: T) -> Self
     where
@@ -92,6 +175,75 @@ impl<T, N: Unsigned> FixedVector<T, N> {
         }
     }
 
+    /// Create a new vector from the elements of `slice`, filling any remaining positions with
+    /// `T::default()`.
+    ///
+    /// Returns `Err` if `slice` is longer than `N`.
+    pub fn from_slice_padded(slice: &[T]) -> Result<Self, Error>
+    where
+        T: Clone + Default,
+    {
+        if slice.len() > Self::capacity() {
+            return Err(Error::OutOfBounds {
+                i: slice.len(),
+                len: Self::capacity(),
+            });
+        }
+
+        let mut vec = Vec::with_capacity(Self::capacity());
+        vec.extend_from_slice(slice);
+        vec.resize_with(Self::capacity(), T::default);
+        Self::new(vec)
+    }
+
+    /// Create a new vector by repeating `pattern` until it is filled.
+    ///
+    /// `pattern` must tile `N` exactly: returns `Error::InvalidChunkLength` if `pattern` is empty
+    /// or if `N` is not a multiple of `pattern.len()`.
+    pub fn from_pattern(pattern: &[T]) -> Result<Self, Error>
+    where
+        T: Clone,
+    {
+        if pattern.is_empty() || Self::capacity() % pattern.len() != 0 {
+            return Err(Error::InvalidChunkLength {
+                len: Self::capacity(),
+                chunk_len: pattern.len(),
+            });
+        }
+
+        Self::new(
+            pattern
+                .iter()
+                .cycle()
+                .take(Self::capacity())
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Create a new vector from consecutive chunks of `C` elements, where `f(chunk_index)`
+    /// returns the chunk at `chunk_index * C..(chunk_index + 1) * C`.
+    ///
+    /// Returns `Error::InvalidChunkLength` without calling `f` if `C` is zero or if `N` is not a
+    /// multiple of `C`.
+    pub fn from_chunks_with<const C: usize, F>(mut f: F) -> Result<Self, Error>
+    where
+        F: FnMut(usize) -> [T; C],
+    {
+        if C == 0 || Self::capacity() % C != 0 {
+            return Err(Error::InvalidChunkLength {
+                len: Self::capacity(),
+                chunk_len: C,
+            });
+        }
+
+        let mut vec = Vec::with_capacity(Self::capacity());
+        for chunk_index in 0..Self::capacity() / C {
+            vec.extend(f(chunk_index));
+        }
+        Self::new(vec)
+    }
+
     /// Identical to `self.capacity`, returns the type-level constant length.
     ///
     /// Exists for compatibility with `Vec`.
@@ -108,6 +260,207 @@ impl<T, N: Unsigned> FixedVector<T, N> {
     pub fn capacity() -> usize {
         N::to_usize()
     }
+
+    /// Returns the type-level length bound `N`, as per `capacity`.
+    ///
+    /// Named identically on `FixedVector` and `VariableList` for use in code which is generic
+    /// over both.
+    pub const fn type_len() -> usize {
+        N::USIZE
+    }
+
+    /// Returns the approximate number of heap bytes held by `self`: the allocated capacity of the
+    /// backing `Vec` multiplied by `size_of::<T>()`.
+    ///
+    /// Heap data owned by the elements themselves (e.g., nested lists) is not included.
+    pub fn mem_usage(&self) -> usize {
+        self.vec.capacity() * std::mem::size_of::<T>()
+    }
+
+    /// Returns mutable references to the elements at each of `indices` at once.
+    ///
+    /// Returns `Error::OutOfBounds { i, len }` if an index `i` is not less than `len`, or
+    /// `Error::DuplicateIndex { i }` if an index `i` appears more than once in `indices`.
+    pub fn get_disjoint_mut<const K: usize>(
+        &mut self,
+        indices: [usize; K],
+    ) -> Result<[&mut T; K], Error> {
+        let len = self.len();
+        self.vec.get_disjoint_mut(indices).map_err(|e| match e {
+            GetDisjointMutError::IndexOutOfBounds => Error::OutOfBounds {
+                i: indices.iter().copied().find(|&i| i >= len).unwrap_or(len),
+                len,
+            },
+            GetDisjointMutError::OverlappingIndices => Error::DuplicateIndex {
+                i: indices
+                    .iter()
+                    .enumerate()
+                    .find(|(n, i)| indices[..*n].contains(i))
+                    .map_or(0, |(_, &i)| i),
+            },
+        })
+    }
+
+    /// Returns the elements of `self` within `range`, or an `Err` rather than panicking if `range`
+    /// is out of bounds.
+    ///
+    /// Returns `Error::OutOfBounds { i: end, len }` if `range` ends past the end of `self`, or
+    /// `Error::InvalidRange { start, end }` if `range` starts after it ends.
+    pub fn try_slice<R: RangeBounds<usize>>(&self, range: R) -> Result<&[T], Error> {
+        Ok(&self.vec[checked_range(range, self.len())?])
+    }
+
+    /// Returns a mutable reference to the first element, or `None` if `self` is empty.
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.vec.first_mut()
+    }
+
+    /// Returns a mutable reference to the last element, or `None` if `self` is empty.
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.vec.last_mut()
+    }
+
+    /// Returns `true` if `iter` yields exactly the elements of `self`, in order.
+    ///
+    /// Avoids collecting `iter` just to compare it.
+    pub fn eq_iter<I>(&self, iter: I) -> bool
+    where
+        T: PartialEq,
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+        self.vec
+            .iter()
+            .all(|item| iter.next().is_some_and(|other| *item == other))
+            && iter.next().is_none()
+    }
+
+    /// Returns an iterator over the elements of `self`, from last to first.
+    pub fn iter_rev(&self) -> std::iter::Rev<std::slice::Iter<'_, T>> {
+        self.vec.iter().rev()
+    }
+
+    /// Returns an owning iterator over the elements of `self`, from last to first.
+    pub fn into_iter_rev(self) -> std::iter::Rev<FixedVectorIntoIter<T>> {
+        self.into_iter().rev()
+    }
+
+    /// Converts `self` into a boxed slice, dropping any excess capacity.
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        self.vec.into_boxed_slice()
+    }
+
+    /// Converts `self` into a reference-counted slice, for sharing without cloning the elements.
+    pub fn into_arc_slice(self) -> Arc<[T]> {
+        Arc::from(self.vec)
+    }
+
+    /// Returns `true` if the elements of `self` are sorted in ascending order.
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.vec.is_sorted()
+    }
+
+    /// Returns `true` if `compare` returns `true` for every pair of consecutive elements.
+    pub fn is_sorted_by<F>(&self, compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.vec.is_sorted_by(compare)
+    }
+
+    /// Returns `true` if the keys extracted by `f` are sorted in ascending order.
+    pub fn is_sorted_by_key<K, F>(&self, f: F) -> bool
+    where
+        F: FnMut(&T) -> K,
+        K: PartialOrd,
+    {
+        self.vec.is_sorted_by_key(f)
+    }
+
+    /// Returns the sum of all elements in `self`.
+    pub fn sum(&self) -> T
+    where
+        T: Copy + std::iter::Sum,
+    {
+        self.vec.iter().copied().sum()
+    }
+
+    /// Returns the greatest element in `self`, or `None` if `self` is empty.
+    pub fn max(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.vec.iter().max()
+    }
+
+    /// Returns the least element in `self`, or `None` if `self` is empty.
+    pub fn min(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.vec.iter().min()
+    }
+
+    /// Clones `src` into `self`, starting at position `offset`.
+    ///
+    /// Returns `Err` and leaves `self` unchanged if `src` would run past the end of `self`.
+    pub fn overwrite_from(&mut self, offset: usize, src: &[T]) -> Result<(), Error>
+    where
+        T: Clone,
+    {
+        let end = offset.saturating_add(src.len());
+        if end > self.len() {
+            return Err(Error::OutOfBounds {
+                i: end,
+                len: self.len(),
+            });
+        }
+        self.vec[offset..end].clone_from_slice(src);
+        Ok(())
+    }
+
+    /// Returns the tree hash root of a `VariableList<T, N>` holding the same elements as `self`.
+    ///
+    /// A `VariableList` and a `FixedVector` with the same `T`, `N` and elements share the same
+    /// Merkle tree of leaves. The list root is that tree's root mixed in with the length, i.e.
+    /// `mix_in_length(&self.tree_hash_root(), N)`.
+    pub fn as_variable_list_root(&self) -> Hash256
+    where
+        T: tree_hash::TreeHash,
+    {
+        tree_hash::mix_in_length(&vec_tree_hash_root::<T, N>(&self.vec), self.len())
+    }
+
+    /// Moves the elements of `self` into an array of length `M`.
+    ///
+    /// Returns `Error::OutOfBounds { i: M, len: N }` if `M` does not equal `N`.
+    pub fn into_array<const M: usize>(self) -> Result<[T; M], Error> {
+        if M != Self::capacity() {
+            return Err(Error::OutOfBounds {
+                i: M,
+                len: Self::capacity(),
+            });
+        }
+        self.vec
+            .try_into()
+            .map_err(|vec: Vec<T>| Error::OutOfBounds {
+                i: M,
+                len: vec.len(),
+            })
+    }
+
+    /// Returns the tree hash root of `self`.
+    ///
+    /// Unlike `TreeHash::tree_hash_root`, returns an `Err` rather than panicking if hashing fails.
+    pub fn checked_tree_hash_root(&self) -> Result<Hash256, Error>
+    where
+        T: tree_hash::TreeHash,
+    {
+        checked_vec_tree_hash_root::<T, N>(&self.vec)
+    }
 }
 
 impl<T: Default, N: Unsigned> From<Vec<T>> for FixedVector<T, N> {
@@ -121,16 +474,55 @@ impl<T: Default, N: Unsigned> From<Vec<T>> for FixedVector<T, N> {
     }
 }
 
+impl<N: Unsigned> std::str::FromStr for FixedVector<u8, N> {
+    type Err = HexError;
+
+    /// Parses a hex string, with or without a `0x` prefix.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(decode_hex(s)?).map_err(HexError::InvalidLength)
+    }
+}
+
+impl<A: smallvec::Array, N: Unsigned> TryFrom<SmallVec<A>> for FixedVector<A::Item, N> {
+    type Error = Error;
+
+    /// Checks the length of `smallvec` before moving its elements into a `Vec`, which only
+    /// allocates if `smallvec` has not already spilled onto the heap.
+    fn try_from(smallvec: SmallVec<A>) -> Result<Self, Self::Error> {
+        if smallvec.len() != Self::capacity() {
+            return Err(Error::OutOfBounds {
+                i: smallvec.len(),
+                len: Self::capacity(),
+            });
+        }
+        Self::new(smallvec.into_vec())
+    }
+}
+
 impl<T, N: Unsigned> From<FixedVector<T, N>> for Vec<T> {
     fn from(vector: FixedVector<T, N>) -> Vec<T> {
         vector.vec
     }
 }
 
-impl<T: Default, N: Unsigned> Default for FixedVector<T, N> {
+impl<T, N: Unsigned> From<FixedVector<T, N>> for Box<[T]> {
+    fn from(vector: FixedVector<T, N>) -> Box<[T]> {
+        vector.into_boxed_slice()
+    }
+}
+
+impl<T, N: Unsigned> From<FixedVector<T, N>> for Arc<[T]> {
+    fn from(vector: FixedVector<T, N>) -> Arc<[T]> {
+        vector.into_arc_slice()
+    }
+}
+
+impl<T: Default + Clone, N: Unsigned> Default for FixedVector<T, N> {
     fn default() -> Self {
+        // `vec!` clones a single default value, which can be a single zeroed allocation for
+        // primitive `T`, rather than calling `T::default` `N` times.
         Self {
-            vec: (0..N::to_usize()).map(|_| T::default()).collect(),
+            vec: vec![T::default(); N::to_usize()],
             _phantom: PhantomData,
         }
     }
@@ -181,10 +573,114 @@ impl<'a, T, N: Unsigned> IntoIterator for &'a FixedVector<T, N> {
 
 impl<T, N: Unsigned> IntoIterator for FixedVector<T, N> {
     type Item = T;
-    type IntoIter = std::vec::IntoIter<T>;
+    type IntoIter = FixedVectorIntoIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.vec.into_iter()
+        FixedVectorIntoIter {
+            inner: self.vec.into_iter(),
+        }
+    }
+}
+
+/// An owning iterator over the elements of a `FixedVector`, created by `into_iter`.
+#[derive(Debug, Clone)]
+pub struct FixedVectorIntoIter<T> {
+    inner: std::vec::IntoIter<T>,
+}
+
+impl<T> FixedVectorIntoIter<T> {
+    /// Returns the remaining elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        self.inner.as_slice()
+    }
+}
+
+impl<T> Iterator for FixedVectorIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for FixedVectorIntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for FixedVectorIntoIter<T> {}
+
+impl<T> FusedIterator for FixedVectorIntoIter<T> {}
+
+struct FixedVectorVisitor<T, N> {
+    _phantom: PhantomData<(T, N)>,
+}
+
+impl<'de, T, N> serde::de::Visitor<'de> for FixedVectorVisitor<T, N>
+where
+    T: Deserialize<'de>,
+    N: Unsigned,
+{
+    type Value = FixedVector<T, N>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "a list of {} elements for {}",
+            N::to_usize(),
+            list_type_name::<N>("FixedVector", type_name::<T>())
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let fixed_len = N::to_usize();
+
+        // Don't trust the size hint beyond `N`, it may come from an untrusted length prefix.
+        let mut vec = Vec::with_capacity(seq.size_hint().map_or(0, |hint| hint.min(fixed_len)));
+
+        while let Some(item) = seq.next_element()? {
+            // Bail out as soon as the length tries to exceed the limit, rather than
+            // deserializing the remainder of the sequence.
+            if vec.len() >= fixed_len {
+                return Err(A::Error::invalid_length(fixed_len + 1, &self));
+            }
+            vec.push(item);
+        }
+
+        if vec.len() != fixed_len {
+            return Err(A::Error::invalid_length(vec.len(), &self));
+        }
+
+        FixedVector::new(vec).map_err(|e| {
+            A::Error::custom(format!(
+                "{}: {:?}",
+                list_type_name::<N>("FixedVector", type_name::<T>()),
+                e
+            ))
+        })
+    }
+}
+
+impl<'de, T, N> Deserialize<'de> for FixedVector<T, N>
+where
+    T: Deserialize<'de>,
+    N: Unsigned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(FixedVectorVisitor {
+            _phantom: PhantomData,
+        })
     }
 }
 
@@ -249,6 +745,22 @@ where
     }
 }
 
+impl<T, N: Unsigned> FixedVector<T, N>
+where
+    T: ssz::Encode,
+{
+    /// Appends the SSZ encoding of `self` to `buf`, as per `ssz::Encode::ssz_append`, and returns
+    /// the number of bytes appended.
+    ///
+    /// This avoids calling `ssz_bytes_len` separately, which visits every element again when `T`
+    /// is variable-length.
+    pub fn ssz_append_returning_len(&self, buf: &mut Vec<u8>) -> usize {
+        let start = buf.len();
+        ssz::Encode::ssz_append(self, buf);
+        buf.len() - start
+    }
+}
+
 impl<T, N: Unsigned> ssz::TryFromIter<T> for FixedVector<T, N> {
     type Error = Error;
 
@@ -267,10 +779,7 @@ impl<T, N: Unsigned> ssz::TryFromIter<T> for FixedVector<T, N> {
             // Bail out as soon as the length tries to exceed the limit. This guards against
             // memory denial-of-service attacks.
             if vec.len() >= n {
-                return Err(Error::OutOfBounds {
-                    i: vec.len(),
-                    len: n,
-                });
+                return Err(Error::OutOfBounds { i: n + 1, len: n });
             }
             vec.push(item);
         }
@@ -303,15 +812,14 @@ where
                 expected: 1,
             })
         } else if T::is_ssz_fixed_len() {
-            let num_items = bytes
-                .len()
-                .checked_div(T::ssz_fixed_len())
-                .ok_or(ssz::DecodeError::ZeroLengthItem)?;
+            let num_items = fixed_len_item_count::<T>(bytes)?;
 
             if num_items != fixed_len {
                 return Err(ssz::DecodeError::BytesInvalid(format!(
-                    "FixedVector of {} items has {} items",
-                    num_items, fixed_len
+                    "{}: {} items does not match length {}",
+                    list_type_name::<N>("FixedVector", type_name::<T>()),
+                    num_items,
+                    fixed_len
                 )));
             }
 
@@ -324,7 +832,8 @@ where
             )?;
             Self::new(vec).map_err(|e| {
                 ssz::DecodeError::BytesInvalid(format!(
-                    "Wrong number of FixedVector elements: {:?}",
+                    "{}: wrong number of elements: {:?}",
+                    list_type_name::<N>("FixedVector", type_name::<T>()),
                     e
                 ))
             })
@@ -332,7 +841,8 @@ where
             let vec = ssz::decode_list_of_variable_length_items(bytes, Some(fixed_len))?;
             Self::new(vec).map_err(|e| {
                 ssz::DecodeError::BytesInvalid(format!(
-                    "Wrong number of FixedVector elements: {:?}",
+                    "{}: wrong number of elements: {:?}",
+                    list_type_name::<N>("FixedVector", type_name::<T>()),
                     e
                 ))
             })
@@ -340,6 +850,93 @@ where
     }
 }
 
+impl<T, N> BoundedDecode for FixedVector<T, N>
+where
+    T: BoundedDecode,
+    N: Unsigned,
+{
+    fn from_ssz_bytes_with_budget(
+        bytes: &[u8],
+        budget: &mut usize,
+    ) -> Result<Self, ssz::DecodeError> {
+        if bytes.is_empty() {
+            return Err(ssz::DecodeError::InvalidByteLength {
+                len: 0,
+                expected: 1,
+            });
+        }
+
+        let vec = decode_list_with_budget(
+            bytes,
+            N::to_usize(),
+            budget,
+            &list_type_name::<N>("FixedVector", type_name::<T>()),
+        )?;
+        Self::new(vec).map_err(|e| {
+            ssz::DecodeError::BytesInvalid(format!(
+                "{}: wrong number of elements: {:?}",
+                list_type_name::<N>("FixedVector", type_name::<T>()),
+                e
+            ))
+        })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<T: crate::SszPod, N: Unsigned> FixedVector<T, N> {
+    /// Returns the elements of `self` as a single byte slice, without copying.
+    ///
+    /// Since `T: SszPod`, the returned bytes are identical to the SSZ encoding of `self`.
+    pub fn as_byte_slice(&self) -> &[u8] {
+        bytemuck::cast_slice(&self.vec)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<N: Unsigned> FixedVector<u8, N> {
+    /// Builds a vector from `bytes`, which must have a length of exactly `N`.
+    ///
+    /// The length is checked before conversion. The buffer is reused without copying if `bytes`
+    /// is the only reference to it.
+    pub fn from_bytes_buf(bytes: bytes::Bytes) -> Result<Self, Error> {
+        if bytes.len() != Self::capacity() {
+            return Err(Error::OutOfBounds {
+                i: bytes.len(),
+                len: Self::capacity(),
+            });
+        }
+        Self::new(bytes.into())
+    }
+
+    /// Converts `self` into a reference-counted `Bytes`, without copying.
+    pub fn into_bytes(self) -> bytes::Bytes {
+        self.vec.into()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: Send, N: Unsigned> FixedVector<T, N> {
+    /// Builds a vector from a parallel iterator which must yield exactly `N` items.
+    ///
+    /// If the parallel iterator has a known length, it is checked before any items are computed.
+    /// Otherwise, all items are collected before the length is checked.
+    pub fn try_from_par_iter<I>(iter: I) -> Result<Self, Error>
+    where
+        I: rayon::iter::IntoParallelIterator<Item = T>,
+    {
+        use rayon::iter::ParallelIterator;
+
+        let iter = iter.into_par_iter();
+        if let Some(len) = iter.opt_len().filter(|&len| len != N::to_usize()) {
+            return Err(Error::OutOfBounds {
+                i: len,
+                len: N::to_usize(),
+            });
+        }
+        Self::new(iter.collect())
+    }
+}
+
 #[cfg(feature = "arbitrary")]
 impl<'a, T: arbitrary::Arbitrary<'a>, N: 'static + Unsigned> arbitrary::Arbitrary<'a>
     for FixedVector<T, N>
@@ -350,13 +947,51 @@ impl<'a, T: arbitrary::Arbitrary<'a>, N: 'static + Unsigned> arbitrary::Arbitrar
         for _ in 0..size {
             vec.push(<T>::arbitrary(u)?);
         }
-        Ok(Self::new(vec).map_err(|_| arbitrary::Error::IncorrectFormat)?)
+        Self::new(vec).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<T, N> FixedVector<T, N>
+where
+    T: std::fmt::Debug,
+    N: std::fmt::Debug + Unsigned,
+{
+    /// Returns a `proptest` strategy which generates vectors of exactly `N` elements drawn from
+    /// `element`.
+    pub fn strategy<S>(element: S) -> impl proptest::strategy::Strategy<Value = Self>
+    where
+        S: proptest::strategy::Strategy<Value = T>,
+    {
+        use proptest::strategy::Strategy;
+
+        proptest::collection::vec(element, N::to_usize()).prop_map(|vec| Self {
+            vec,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<T, N> proptest::arbitrary::Arbitrary for FixedVector<T, N>
+where
+    T: proptest::arbitrary::Arbitrary + 'static,
+    N: std::fmt::Debug + Unsigned + 'static,
+{
+    type Parameters = T::Parameters;
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        Self::strategy(proptest::arbitrary::any_with::<T>(args)).boxed()
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::VariableList;
     use ssz::*;
     use std::collections::HashSet;
     use tree_hash::{merkle_root, TreeHash};
@@ -378,6 +1013,253 @@ mod test {
         assert!(fixed.is_ok());
     }
 
+    #[test]
+    fn from_cow() {
+        let bytes = vec![1_u8, 2, 3, 4];
+
+        let fixed: FixedVector<u8, U4> = FixedVector::from_cow(Cow::Borrowed(&bytes)).unwrap();
+        assert_eq!(&fixed[..], &bytes[..]);
+
+        let ptr = bytes.as_ptr();
+        let fixed: FixedVector<u8, U4> = FixedVector::from_cow(Cow::Owned(bytes)).unwrap();
+        assert_eq!(fixed.as_ptr(), ptr);
+
+        assert_eq!(
+            FixedVector::<u8, U4>::from_cow(Cow::Borrowed(&[1, 2, 3])),
+            Err(Error::OutOfBounds { i: 3, len: 4 })
+        );
+    }
+
+    #[test]
+    fn from_slice_padded() {
+        let fixed: FixedVector<u64, U4> = FixedVector::from_slice_padded(&[1, 2]).unwrap();
+        assert_eq!(&fixed[..], &[1, 2, 0, 0]);
+
+        let fixed: FixedVector<u64, U4> = FixedVector::from_slice_padded(&[1, 2, 3, 4]).unwrap();
+        assert_eq!(&fixed[..], &[1, 2, 3, 4]);
+
+        let fixed: FixedVector<u64, U4> = FixedVector::from_slice_padded(&[]).unwrap();
+        assert_eq!(&fixed[..], &[0, 0, 0, 0]);
+
+        assert_eq!(
+            FixedVector::<u64, U4>::from_slice_padded(&[1, 2, 3, 4, 5]),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+    }
+
+    #[test]
+    fn from_pattern() {
+        let fixed: FixedVector<u64, U6> = FixedVector::from_pattern(&[1, 2, 3]).unwrap();
+        assert_eq!(&fixed[..], &[1, 2, 3, 1, 2, 3]);
+
+        let fixed: FixedVector<u64, U4> = FixedVector::from_pattern(&[7, 8, 9, 10]).unwrap();
+        assert_eq!(&fixed[..], &[7, 8, 9, 10]);
+
+        assert_eq!(
+            FixedVector::<u64, U4>::from_pattern(&[1, 2, 3]),
+            Err(Error::InvalidChunkLength {
+                len: 4,
+                chunk_len: 3
+            })
+        );
+        assert_eq!(
+            FixedVector::<u64, U4>::from_pattern(&[]),
+            Err(Error::InvalidChunkLength {
+                len: 4,
+                chunk_len: 0
+            })
+        );
+    }
+
+    #[test]
+    fn from_chunks_with() {
+        let fixed: FixedVector<u8, U6> =
+            FixedVector::from_chunks_with(|i| [i as u8, i as u8 * 10]).unwrap();
+        assert_eq!(&fixed[..], &[0, 0, 1, 10, 2, 20]);
+
+        let fixed: FixedVector<u8, U4> = FixedVector::from_chunks_with(|_| [1, 2, 3, 4]).unwrap();
+        assert_eq!(&fixed[..], &[1, 2, 3, 4]);
+
+        let mut calls = 0;
+        assert_eq!(
+            FixedVector::<u8, U4>::from_chunks_with(|_| {
+                calls += 1;
+                [0; 3]
+            }),
+            Err(Error::InvalidChunkLength {
+                len: 4,
+                chunk_len: 3
+            })
+        );
+        assert_eq!(calls, 0);
+        assert_eq!(
+            FixedVector::<u8, U4>::from_chunks_with(|_| []),
+            Err(Error::InvalidChunkLength {
+                len: 4,
+                chunk_len: 0
+            })
+        );
+    }
+
+    #[test]
+    fn zero_length() {
+        assert_eq!(
+            FixedVector::<u64, U0>::new(vec![]),
+            Err(Error::EmptyFixedVector)
+        );
+        assert_eq!(
+            FixedVector::<u64, U0>::try_from_iter(vec![]),
+            Err(Error::EmptyFixedVector)
+        );
+        serde_json::from_str::<FixedVector<u64, U0>>("[]").unwrap_err();
+
+        // The infallible constructors still produce a value, but it cannot be decoded.
+        let fixed: FixedVector<u64, U0> = FixedVector::default();
+        assert!(FixedVector::<u64, U0>::from_ssz_bytes(&fixed.as_ssz_bytes()).is_err());
+    }
+
+    #[test]
+    fn default() {
+        let fixed: FixedVector<u64, U4> = FixedVector::default();
+        assert_eq!(&fixed[..], &[0; 4]);
+
+        let fixed: FixedVector<Vec<u8>, U2> = FixedVector::default();
+        assert_eq!(&fixed[..], &[Vec::<u8>::new(), vec![]]);
+    }
+
+    #[test]
+    fn as_variable_list_root() {
+        let fixed: FixedVector<u64, U4> = vec![1, 2, 3, 4].into();
+        let list: VariableList<u64, U4> = vec![1, 2, 3, 4].into();
+        assert_eq!(fixed.as_variable_list_root(), list.tree_hash_root());
+
+        let fixed: FixedVector<Hash256, U3> = vec![Hash256::repeat_byte(1); 3].into();
+        let list: VariableList<Hash256, U3> = vec![Hash256::repeat_byte(1); 3].into();
+        assert_eq!(fixed.as_variable_list_root(), list.tree_hash_root());
+    }
+
+    #[test]
+    fn out_of_bounds_lengths() {
+        type Fixed = FixedVector<u64, U4>;
+
+        assert_eq!(
+            Fixed::new(vec![0; 5]),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+        assert_eq!(
+            Fixed::new(vec![0; 3]),
+            Err(Error::OutOfBounds { i: 3, len: 4 })
+        );
+        assert_eq!(
+            Fixed::try_from_iter(0..),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+        assert_eq!(
+            Fixed::try_from_iter(0..3),
+            Err(Error::OutOfBounds { i: 3, len: 4 })
+        );
+    }
+
+    #[test]
+    fn from_exact_iter() {
+        let source = [1_u64, 2, 3, 4];
+        let fixed = FixedVector::<u64, U4>::from_exact_iter(source.iter().map(|x| x * 2));
+        assert_eq!(fixed.as_deref(), Ok(&[2, 4, 6, 8][..]));
+
+        assert_eq!(
+            FixedVector::<u64, U4>::from_exact_iter(source[..3].iter().copied()),
+            Err(Error::OutOfBounds { i: 3, len: 4 })
+        );
+        assert_eq!(
+            FixedVector::<u64, U3>::from_exact_iter(source.into_iter()),
+            Err(Error::OutOfBounds { i: 4, len: 3 })
+        );
+    }
+
+    #[test]
+    fn into_iter_named() {
+        let fixed: FixedVector<u64, U4> = vec![1, 2, 3, 4].into();
+        let mut iter: FixedVectorIntoIter<u64> = fixed.into_iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.as_slice(), &[2, 3]);
+        assert_eq!(iter.by_ref().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn get_disjoint_mut() {
+        let mut fixed: FixedVector<u64, U4> = vec![1, 2, 3, 4].into();
+        let [a, b] = fixed.get_disjoint_mut([0, 3]).unwrap();
+        std::mem::swap(a, b);
+        assert_eq!(&fixed[..], &[4, 2, 3, 1]);
+
+        assert_eq!(
+            fixed.get_disjoint_mut([1, 4]).map(|_| ()),
+            Err(Error::OutOfBounds { i: 4, len: 4 })
+        );
+        assert_eq!(
+            fixed.get_disjoint_mut([1, 2, 1]).map(|_| ()),
+            Err(Error::DuplicateIndex { i: 1 })
+        );
+    }
+
+    #[test]
+    fn try_slice() {
+        let fixed: FixedVector<u64, U4> = vec![1, 2, 3, 4].into();
+        assert_eq!(fixed.try_slice(..), Ok(&[1, 2, 3, 4][..]));
+        assert_eq!(fixed.try_slice(1..=2), Ok(&[2, 3][..]));
+        assert_eq!(fixed.try_slice(4..), Ok(&[][..]));
+
+        assert_eq!(
+            fixed.try_slice(2..5),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+        let (start, end) = (3, 2);
+        assert_eq!(
+            fixed.try_slice(start..end),
+            Err(Error::InvalidRange { start: 3, end: 2 })
+        );
+        assert_eq!(
+            fixed.try_slice(..=usize::MAX),
+            Err(Error::OutOfBounds {
+                i: usize::MAX,
+                len: 4
+            })
+        );
+    }
+
+    #[test]
+    fn into_array() {
+        let fixed: FixedVector<u64, U4> = vec![1, 2, 3, 4].into();
+        assert_eq!(fixed.clone().into_array::<4>(), Ok([1, 2, 3, 4]));
+        assert_eq!(
+            fixed.clone().into_array::<3>(),
+            Err(Error::OutOfBounds { i: 3, len: 4 })
+        );
+        assert_eq!(
+            fixed.into_array::<5>(),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+    }
+
+    #[test]
+    fn try_from_iter_exact() {
+        let fixed: FixedVector<u64, U4> = FixedVector::try_from_iter_exact(0..4).unwrap();
+        assert_eq!(&fixed[..], &[0, 1, 2, 3]);
+
+        assert_eq!(
+            FixedVector::<u64, U4>::try_from_iter_exact(0..3),
+            Err(Error::OutOfBounds { i: 3, len: 4 })
+        );
+        assert_eq!(
+            FixedVector::<u64, U4>::try_from_iter_exact(0..),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+    }
+
     #[test]
     fn indexing() {
         let vec = vec![1, 2];
@@ -418,6 +1300,113 @@ mod test {
         assert_eq!(fixed.get(4), None);
     }
 
+    #[test]
+    fn first_last_mut() {
+        let mut fixed: FixedVector<u64, U4> = FixedVector::from(vec![0, 2, 4, 6]);
+
+        *fixed.first_mut().unwrap() += 1;
+        *fixed.last_mut().unwrap() += 1;
+        assert_eq!(&fixed[..], &[1, 2, 4, 7]);
+
+        let mut empty: FixedVector<u64, U0> = FixedVector::from(vec![]);
+        assert_eq!(empty.first_mut(), None);
+        assert_eq!(empty.last_mut(), None);
+    }
+
+    #[test]
+    fn sum_max_min() {
+        let fixed: FixedVector<u64, U4> = FixedVector::from(vec![3, 1, 4, 2]);
+        assert_eq!(fixed.sum(), 10);
+        assert_eq!(fixed.max(), Some(&4));
+        assert_eq!(fixed.min(), Some(&1));
+
+        let empty: FixedVector<u64, U0> = FixedVector::from(vec![]);
+        assert_eq!(empty.sum(), 0);
+        assert_eq!(empty.max(), None);
+        assert_eq!(empty.min(), None);
+    }
+
+    #[test]
+    fn from_str() {
+        let fixed: FixedVector<u8, U4> = "0xdeadbeef".parse().unwrap();
+        assert_eq!(&fixed[..], &[0xde, 0xad, 0xbe, 0xef]);
+
+        // Uppercase and without a prefix.
+        let fixed: FixedVector<u8, U4> = "DEADBEEF".parse().unwrap();
+        assert_eq!(&fixed[..], &[0xde, 0xad, 0xbe, 0xef]);
+
+        assert!(matches!(
+            "0xdeadbee".parse::<FixedVector<u8, U4>>(),
+            Err(HexError::InvalidHex(_))
+        ));
+        assert!(matches!(
+            "0xzz".parse::<FixedVector<u8, U1>>(),
+            Err(HexError::InvalidHex(_))
+        ));
+        assert_eq!(
+            "0xdead".parse::<FixedVector<u8, U4>>(),
+            Err(HexError::InvalidLength(Error::OutOfBounds { i: 2, len: 4 }))
+        );
+    }
+
+    #[test]
+    fn serde_length_bounds() {
+        let fixed: FixedVector<u64, U4> = serde_json::from_str("[1, 2, 3, 4]").unwrap();
+        assert_eq!(&fixed[..], &[1, 2, 3, 4]);
+        assert_eq!(serde_json::to_string(&fixed).unwrap(), "[1,2,3,4]");
+
+        let err = serde_json::from_str::<FixedVector<u64, U4>>("[1, 2, 3]").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("invalid length 3, expected a list of 4 elements"));
+        let err = serde_json::from_str::<FixedVector<u64, U4>>("[1, 2, 3, 4, 5]").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("invalid length 5, expected a list of 4 elements"));
+        assert!(err
+            .to_string()
+            .contains("expected a list of 4 elements for FixedVector<u64, 4>"));
+    }
+
+    #[test]
+    fn eq_iter() {
+        let fixed: FixedVector<u64, U4> = FixedVector::from(vec![0, 2, 4, 6]);
+
+        assert!(fixed.eq_iter((0..4).map(|i| i * 2)));
+        assert!(!fixed.eq_iter((0..3).map(|i| i * 2)));
+        assert!(!fixed.eq_iter((0..5).map(|i| i * 2)));
+        assert!(!fixed.eq_iter(vec![0, 2, 4, 7]));
+    }
+
+    #[test]
+    fn try_from_smallvec() {
+        let small: SmallVec<[u64; 4]> = smallvec::smallvec![1, 2, 3, 4];
+        let fixed: FixedVector<u64, U4> = FixedVector::try_from(small).unwrap();
+        assert_eq!(&fixed[..], &[1, 2, 3, 4]);
+
+        let small: SmallVec<[u64; 4]> = smallvec::smallvec![1, 2, 3];
+        assert_eq!(
+            FixedVector::<u64, U4>::try_from(small),
+            Err(Error::OutOfBounds { i: 3, len: 4 })
+        );
+
+        // Spilled onto the heap.
+        let small: SmallVec<[u64; 2]> = smallvec::smallvec![1, 2, 3, 4];
+        let fixed: FixedVector<u64, U4> = FixedVector::try_from(small).unwrap();
+        assert_eq!(&fixed[..], &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn iter_rev() {
+        let fixed: FixedVector<u64, U4> = FixedVector::from(vec![0, 2, 4, 6]);
+
+        assert_eq!(
+            fixed.iter_rev().copied().collect::<Vec<_>>(),
+            vec![6, 4, 2, 0]
+        );
+        assert_eq!(fixed.into_iter_rev().collect::<Vec<_>>(), vec![6, 4, 2, 0]);
+    }
+
     #[test]
     fn iterator() {
         let vec = vec![0, 2, 4, 6];
@@ -448,6 +1437,33 @@ mod test {
         ssz_round_trip::<FixedVector<u16, U8>>(vec![0; 8].into());
     }
 
+    #[test]
+    fn ssz_decode_rejects_partial_item() {
+        assert_eq!(
+            FixedVector::<u16, U4>::from_ssz_bytes(&[0; 9]),
+            Err(DecodeError::InvalidByteLength {
+                len: 9,
+                expected: 8
+            })
+        );
+    }
+
+    #[test]
+    fn ssz_decode_error_names_type() {
+        let err = FixedVector::<u16, U4>::from_ssz_bytes(&[0; 6]).unwrap_err();
+        let DecodeError::BytesInvalid(msg) = err else {
+            panic!("unexpected error {:?}", err);
+        };
+        assert_eq!(msg, "FixedVector<u16, 4>: 3 items does not match length 4");
+    }
+
+    #[test]
+    fn ssz_encode_bool_is_not_bit_packed() {
+        let vec: FixedVector<bool, U4> = vec![true, false, true, true].into();
+        assert_eq!(vec.as_ssz_bytes(), vec![1, 0, 1, 1]);
+        ssz_round_trip(vec);
+    }
+
     #[test]
     fn tree_hash_u8() {
         let fixed: FixedVector<u8, U0> = FixedVector::from(vec![]);
@@ -515,6 +1531,35 @@ mod test {
         );
     }
 
+    #[test]
+    fn overwrite_from() {
+        let mut fixed: FixedVector<u64, U4> = FixedVector::from(vec![1, 2, 3, 4]);
+
+        fixed.overwrite_from(1, &[5, 6]).unwrap();
+        assert_eq!(&fixed[..], &[1, 5, 6, 4]);
+
+        // Exact fit at the very end.
+        fixed.overwrite_from(2, &[7, 8]).unwrap();
+        assert_eq!(&fixed[..], &[1, 5, 7, 8]);
+
+        // One element past the end.
+        assert_eq!(
+            fixed.overwrite_from(3, &[9, 9]),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+        assert_eq!(&fixed[..], &[1, 5, 7, 8]);
+    }
+
+    #[test]
+    fn checked_tree_hash_root() {
+        let fixed: FixedVector<u64, U4> = FixedVector::from(vec![1, 2, 3, 4]);
+        assert_eq!(fixed.checked_tree_hash_root(), Ok(fixed.tree_hash_root()));
+
+        let a = A { a: 0, b: 1 };
+        let fixed: FixedVector<A, U13> = FixedVector::from(vec![a; 13]);
+        assert_eq!(fixed.checked_tree_hash_root(), Ok(fixed.tree_hash_root()));
+    }
+
     #[test]
     fn std_hash() {
         let x: FixedVector<u32, U16> = FixedVector::from(vec![3; 16]);
@@ -528,4 +1573,105 @@ mod test {
         }
         assert_eq!(hashset.len(), 2);
     }
+
+    #[test]
+    fn std_hash_ignores_capacity() {
+        use std::hash::BuildHasher;
+
+        let mut vec = Vec::with_capacity(64);
+        vec.extend([1_u32, 2, 3, 4]);
+        let x: FixedVector<u32, U4> = FixedVector::new(vec).unwrap();
+        let y: FixedVector<u32, U4> = FixedVector::new(vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(x, y);
+
+        let state = std::collections::hash_map::RandomState::new();
+        assert_eq!(state.hash_one(&x), state.hash_one(&y));
+    }
+
+    #[test]
+    fn type_len() {
+        const LEN: usize = FixedVector::<u8, U16>::type_len();
+        assert_eq!(LEN, FixedVector::<u8, U16>::capacity());
+        assert_eq!(LEN, VariableList::<u8, U16>::type_len());
+    }
+
+    #[test]
+    fn mem_usage() {
+        let fixed: FixedVector<u16, U16> = FixedVector::from_elem(0);
+        assert_eq!(fixed.mem_usage(), 32);
+
+        let fixed: FixedVector<u64, U4> = FixedVector::from_elem(0);
+        assert_eq!(fixed.mem_usage(), 32);
+    }
+
+    #[test]
+    fn ssz_append_returning_len() {
+        let mut buf = vec![0xff];
+
+        let fixed: FixedVector<u16, U2> = vec![4, 5].into();
+        assert_eq!(fixed.ssz_append_returning_len(&mut buf), 4);
+
+        let nested: FixedVector<VariableList<u8, U4>, U2> =
+            vec![vec![1].into(), vec![2, 3].into()].into();
+        assert_eq!(
+            nested.ssz_append_returning_len(&mut buf),
+            nested.ssz_bytes_len()
+        );
+
+        let mut expected = vec![0xff];
+        expected.extend(fixed.as_ssz_bytes());
+        expected.extend(nested.as_ssz_bytes());
+        assert_eq!(buf, expected);
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn proptest_length_bounds(fixed in proptest::prelude::any::<FixedVector<u64, U4>>()) {
+            assert_eq!(fixed.len(), 4);
+        }
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn bytes_buf_round_trip() {
+        let fixed = FixedVector::<u8, U4>::from_bytes_buf(bytes::Bytes::from(vec![1, 2, 3, 4]));
+        assert_eq!(fixed, Ok(FixedVector::from(vec![1, 2, 3, 4])));
+        assert_eq!(&fixed.unwrap().into_bytes()[..], &[1, 2, 3, 4]);
+
+        assert_eq!(
+            FixedVector::<u8, U4>::from_bytes_buf(bytes::Bytes::from_static(&[1, 2, 3])),
+            Err(Error::OutOfBounds { i: 3, len: 4 })
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn try_from_par_iter() {
+        use rayon::prelude::*;
+
+        type Fixed = FixedVector<u64, U4>;
+
+        assert_eq!(
+            Fixed::try_from_par_iter((0..4_u64).into_par_iter().map(|x| x * 2)),
+            Ok(Fixed::from(vec![0, 2, 4, 6]))
+        );
+        assert_eq!(
+            Fixed::try_from_par_iter(0..3_u64),
+            Err(Error::OutOfBounds { i: 3, len: 4 })
+        );
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn as_byte_slice() {
+        let fixed: FixedVector<u8, U4> = FixedVector::from(vec![1, 2, 3, 4]);
+        assert_eq!(fixed.as_byte_slice(), &fixed.as_ssz_bytes()[..]);
+
+        #[cfg(target_endian = "little")]
+        {
+            let fixed: FixedVector<u64, U4> = FixedVector::from(vec![1, u64::MAX, 0, 1 << 40]);
+            assert_eq!(fixed.as_byte_slice(), &fixed.as_ssz_bytes()[..]);
+        }
+    }
 }
@@ -37,16 +37,39 @@
 //!
 //! ```
 
+pub mod bitfield_utils;
+pub mod bitlist_bytes;
+pub mod bitvector_msb;
+mod bounded_decode;
 #[macro_use]
 mod fixed_vector;
+#[macro_use]
+mod macros;
+mod ord_bitfield;
+#[cfg(feature = "bytemuck")]
+mod pod;
 pub mod serde_utils;
 mod tree_hash;
 mod variable_list;
 
-pub use fixed_vector::FixedVector;
+pub use bounded_decode::BoundedDecode;
+pub use fixed_vector::{FixedVector, FixedVectorIntoIter};
+pub use ord_bitfield::OrdBitfield;
+#[cfg(feature = "bytemuck")]
+pub use pod::SszPod;
 pub use ssz::{BitList, BitVector, Bitfield};
 pub use typenum;
-pub use variable_list::VariableList;
+pub use variable_list::{collect_chunked, flatten, VariableList};
+
+use std::ops::{Bound, Range, RangeBounds};
+use typenum::Unsigned;
+
+/// Re-exports used by the exported macros. Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use ssz;
+    pub use tree_hash;
+}
 
 pub mod length {
     pub use ssz::{Fixed, Variable};
@@ -54,18 +77,112 @@ pub mod length {
 
 /// Returned when an item encounters an error.
 #[derive(PartialEq, Debug, Clone)]
+#[non_exhaustive]
 pub enum Error {
-    OutOfBounds {
-        i: usize,
-        len: usize,
-    },
+    /// A length or index is out of bounds.
+    ///
+    /// For lengths, `i` is the length that was given or that the operation would have produced
+    /// (e.g., the length of the `Vec` passed to `new`, or the current length plus one for a
+    /// `push`), and `len` is the length or maximum length that `i` violated. Iterator-based
+    /// constructors stop at the first excess element, so they report `len + 1`.
+    ///
+    /// For indexing operations (e.g., `FixedVector::get_disjoint_mut`), `i` is the offending index
+    /// and `len` is the bound it violated, usually the length of the list.
+    ///
+    /// Errors which don't fit this meaning have their own variants, e.g. `InvalidChunkLength`,
+    /// `InvalidRange` and `DuplicateIndex`.
+    OutOfBounds { i: usize, len: usize },
     /// A `BitList` does not have a set bit, therefore it's length is unknowable.
     MissingLengthInformation,
     /// A `BitList` has excess bits set to true.
     ExcessBits,
     /// A `BitList` has an invalid number of bytes for a given bit length.
-    InvalidByteCount {
-        given: usize,
-        expected: usize,
-    },
+    InvalidByteCount { given: usize, expected: usize },
+    /// A `FixedVector` has a length of zero, which is illegal in SSZ.
+    EmptyFixedVector,
+    /// The tree hasher failed, typically because there are more elements than leaves.
+    TreeHashError(::tree_hash::Error),
+    /// An allocation failed, e.g., in `VariableList::try_from_iter_fallible`.
+    AllocationFailed(std::collections::TryReserveError),
+    /// A length of `len` cannot be divided into chunks of `chunk_len`, either because it is not a
+    /// multiple of `chunk_len` or because `chunk_len` is zero.
+    InvalidChunkLength { len: usize, chunk_len: usize },
+    /// A range starts after it ends.
+    InvalidRange { start: usize, end: usize },
+    /// The index `i` was given more than once where distinct indices are required.
+    DuplicateIndex { i: usize },
+}
+
+impl From<ssz::BitfieldError> for Error {
+    fn from(e: ssz::BitfieldError) -> Self {
+        match e {
+            ssz::BitfieldError::OutOfBounds { i, len } => Error::OutOfBounds { i, len },
+            ssz::BitfieldError::MissingLengthInformation => Error::MissingLengthInformation,
+            ssz::BitfieldError::ExcessBits => Error::ExcessBits,
+            ssz::BitfieldError::InvalidByteCount { given, expected } => {
+                Error::InvalidByteCount { given, expected }
+            }
+        }
+    }
+}
+
+/// Returned when parsing a list of bytes from a hex string.
+#[derive(PartialEq, Debug, Clone)]
+pub enum HexError {
+    /// The string is not valid hex.
+    InvalidHex(String),
+    /// The decoded bytes do not fit the length of the list.
+    InvalidLength(Error),
+}
+
+/// Decodes a hex string, with or without a `0x` prefix.
+fn decode_hex(s: &str) -> Result<Vec<u8>, HexError> {
+    let stripped = s.strip_prefix("0x").unwrap_or(s);
+    ::serde_utils::hex::decode(&format!("0x{}", stripped)).map_err(HexError::InvalidHex)
+}
+
+/// Returns a short name for a list type for use in error messages, e.g. `VariableList<u64, 128>`.
+///
+/// Unlike `std::any::type_name`, the type-level length is printed as a number rather than as its
+/// `typenum` expansion.
+fn list_type_name<N: Unsigned>(list: &str, item: &str) -> String {
+    format!("{}<{}, {}>", list, item, N::to_usize())
+}
+
+/// Converts `range` into a `Range` within `0..len`.
+///
+/// Returns `Error::OutOfBounds { i: end, len }` if the range ends past `len`, or
+/// `Error::InvalidRange { start, end }` if it starts after it ends.
+fn checked_range<R: RangeBounds<usize>>(range: R, len: usize) -> Result<Range<usize>, Error> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .ok_or(Error::OutOfBounds { i: usize::MAX, len })?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .ok_or(Error::OutOfBounds { i: usize::MAX, len })?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    if end > len {
+        Err(Error::OutOfBounds { i: end, len })
+    } else if start > end {
+        Err(Error::InvalidRange { start, end })
+    } else {
+        Ok(start..end)
+    }
+}
+
+/// Returned when building a list from an iterator of fallible elements.
+#[derive(PartialEq, Debug, Clone)]
+pub enum TryFromResultError<E> {
+    /// An element of the iterator was an `Err`.
+    Element(E),
+    /// The list could not hold all of the elements.
+    List(Error),
 }
@@ -0,0 +1,137 @@
+//! Declarative macros exported for use by downstream crates.
+
+/// Implements SSZ `Encode`/`Decode` and `TreeHash` for a single-field tuple struct wrapping a
+/// `FixedVector<u8, N>`, identically to the wrapped vector.
+///
+/// Encoding and decoding copy the bytes in bulk, rather than one element at a time.
+///
+/// The wrapped field must be a `FixedVector<u8, $len>`. A field with any other length fails to
+/// compile, rather than producing an encoding whose length disagrees with `ssz_fixed_len`.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{impl_transparent_byte_vector, typenum::U32, FixedVector};
+///
+/// pub struct Root(pub FixedVector<u8, U32>);
+///
+/// impl_transparent_byte_vector!(Root, U32);
+/// ```
+///
+/// A length which doesn't match the wrapped field is rejected:
+///
+/// ```compile_fail
+/// use ssz_types::{impl_transparent_byte_vector, typenum::{U16, U32}, FixedVector};
+///
+/// pub struct Root(pub FixedVector<u8, U32>);
+///
+/// impl_transparent_byte_vector!(Root, U16);
+/// ```
+#[macro_export]
+macro_rules! impl_transparent_byte_vector {
+    ($type: ty, $len: ty) => {
+        impl $crate::__private::ssz::Encode for $type {
+            fn is_ssz_fixed_len() -> bool {
+                true
+            }
+
+            fn ssz_fixed_len() -> usize {
+                <$len as $crate::typenum::Unsigned>::to_usize()
+            }
+
+            fn ssz_bytes_len(&self) -> usize {
+                let inner: &$crate::FixedVector<u8, $len> = &self.0;
+                inner.len()
+            }
+
+            fn ssz_append(&self, buf: &mut Vec<u8>) {
+                let inner: &$crate::FixedVector<u8, $len> = &self.0;
+                buf.extend_from_slice(inner);
+            }
+        }
+
+        impl $crate::__private::ssz::Decode for $type {
+            fn is_ssz_fixed_len() -> bool {
+                true
+            }
+
+            fn ssz_fixed_len() -> usize {
+                <$len as $crate::typenum::Unsigned>::to_usize()
+            }
+
+            fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, $crate::__private::ssz::DecodeError> {
+                let expected = <$len as $crate::typenum::Unsigned>::to_usize();
+                if bytes.len() != expected {
+                    return Err($crate::__private::ssz::DecodeError::InvalidByteLength {
+                        len: bytes.len(),
+                        expected,
+                    });
+                }
+                $crate::FixedVector::<u8, $len>::new(bytes.to_vec())
+                    .map(Self)
+                    .map_err(|e| {
+                        $crate::__private::ssz::DecodeError::BytesInvalid(format!("{:?}", e))
+                    })
+            }
+        }
+
+        impl $crate::__private::tree_hash::TreeHash for $type {
+            fn tree_hash_type() -> $crate::__private::tree_hash::TreeHashType {
+                $crate::__private::tree_hash::TreeHashType::Vector
+            }
+
+            fn tree_hash_packed_encoding(&self) -> $crate::__private::tree_hash::PackedEncoding {
+                unreachable!("Vector should never be packed.")
+            }
+
+            fn tree_hash_packing_factor() -> usize {
+                unreachable!("Vector should never be packed.")
+            }
+
+            fn tree_hash_root(&self) -> $crate::__private::tree_hash::Hash256 {
+                let inner: &$crate::FixedVector<u8, $len> = &self.0;
+                $crate::__private::tree_hash::TreeHash::tree_hash_root(inner)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::FixedVector;
+    use ssz::{Decode, DecodeError, Encode};
+    use tree_hash::TreeHash;
+    use typenum::U4;
+
+    #[derive(Debug, PartialEq)]
+    struct Wrapper(FixedVector<u8, U4>);
+
+    impl_transparent_byte_vector!(Wrapper, U4);
+
+    #[test]
+    fn matches_inner_vector() {
+        let inner: FixedVector<u8, U4> = vec![1, 2, 3, 4].into();
+        let wrapper = Wrapper(inner.clone());
+
+        assert_eq!(wrapper.as_ssz_bytes(), inner.as_ssz_bytes());
+        assert_eq!(wrapper.ssz_bytes_len(), inner.ssz_bytes_len());
+        assert_eq!(
+            <Wrapper as Encode>::ssz_fixed_len(),
+            <FixedVector<u8, U4> as Encode>::ssz_fixed_len()
+        );
+        assert_eq!(wrapper.tree_hash_root(), inner.tree_hash_root());
+        assert_eq!(Wrapper::from_ssz_bytes(&[1, 2, 3, 4]), Ok(wrapper));
+    }
+
+    #[test]
+    fn decode_wrong_length() {
+        assert_eq!(
+            Wrapper::from_ssz_bytes(&[1, 2, 3]),
+            Err(DecodeError::InvalidByteLength {
+                len: 3,
+                expected: 4
+            })
+        );
+        assert!(Wrapper::from_ssz_bytes(&[0; 5]).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+//! A total order for `BitList` and `BitVector`, which are defined in `ethereum_ssz`.
+use crate::length::{Fixed, Variable};
+use crate::Bitfield;
+use std::cmp::Ordering;
+use std::ops::Deref;
+use typenum::Unsigned;
+
+/// Wraps a `Bitfield` to provide `Ord`, allowing it to be stored in a `BTreeSet` or used as a
+/// `BTreeMap` key.
+///
+/// Bitfields are ordered first by `len()` and then lexicographically by their bytes. `Bitfield`
+/// always keeps the bits beyond `len()` cleared, so two bitfields compare `Equal` exactly when
+/// they are `==`.
+///
+/// ## Note
+///
+/// The order is deterministic but not otherwise meaningful. In particular, it is _not_ based on
+/// `is_subset`.
+#[derive(Clone, Debug)]
+pub struct OrdBitfield<T>(pub Bitfield<T>);
+
+impl<T> OrdBitfield<T> {
+    /// Returns the wrapped bitfield.
+    pub fn into_inner(self) -> Bitfield<T> {
+        self.0
+    }
+}
+
+impl<T> From<Bitfield<T>> for OrdBitfield<T> {
+    fn from(bitfield: Bitfield<T>) -> Self {
+        Self(bitfield)
+    }
+}
+
+impl<T> Deref for OrdBitfield<T> {
+    type Target = Bitfield<T>;
+
+    fn deref(&self) -> &Bitfield<T> {
+        &self.0
+    }
+}
+
+impl<T> PartialEq for OrdBitfield<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl<T> Eq for OrdBitfield<T> {}
+impl<T> std::hash::Hash for OrdBitfield<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+macro_rules! impl_ord {
+    ($behaviour: ident) => {
+        impl<N: Unsigned + Clone> Ord for OrdBitfield<$behaviour<N>> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0
+                    .len()
+                    .cmp(&other.0.len())
+                    .then_with(|| self.0.as_slice().cmp(other.0.as_slice()))
+            }
+        }
+
+        impl<N: Unsigned + Clone> PartialOrd for OrdBitfield<$behaviour<N>> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+    };
+}
+
+impl_ord!(Variable);
+impl_ord!(Fixed);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{BitList, BitVector};
+    use std::collections::BTreeSet;
+    use typenum::{U13, U16};
+
+    fn bitlist(len: usize, set: &[usize]) -> OrdBitfield<Variable<U16>> {
+        let mut bitfield = BitList::<U16>::with_capacity(len).unwrap();
+        for &i in set {
+            bitfield.set(i, true).unwrap();
+        }
+        bitfield.into()
+    }
+
+    #[test]
+    fn bitlist_order() {
+        assert!(bitlist(3, &[0, 1, 2]) < bitlist(4, &[]));
+        assert!(bitlist(12, &[0]) < bitlist(12, &[1]));
+        // Bit 9 is in the second byte, which is compared after the first.
+        assert!(bitlist(12, &[9]) < bitlist(12, &[0]));
+        assert_eq!(
+            bitlist(12, &[3, 9]).cmp(&bitlist(12, &[9, 3])),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn btree_set_dedup() {
+        let set: BTreeSet<_> = [
+            bitlist(5, &[1]),
+            bitlist(5, &[1]),
+            bitlist(5, &[2]),
+            bitlist(6, &[1]),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 3);
+
+        let mut a = BitVector::<U13>::new();
+        a.set(12, true).unwrap();
+        let set: BTreeSet<_> = [a.clone(), BitVector::new(), a]
+            .into_iter()
+            .map(OrdBitfield::from)
+            .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.first().unwrap().is_zero());
+    }
+}
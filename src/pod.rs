@@ -0,0 +1,27 @@
+//! Marker trait for element types which can be viewed directly as SSZ bytes.
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A plain-old-data type whose SSZ encoding is identical to its in-memory representation.
+///
+/// SSZ encodes integers as little-endian, so multi-byte integers only implement this trait on
+/// little-endian targets. `u8` implements it everywhere.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait SszPod: bytemuck::Pod + sealed::Sealed {}
+
+macro_rules! impl_ssz_pod {
+    ($($type: ty),*) => {
+        $(
+            impl sealed::Sealed for $type {}
+            impl SszPod for $type {}
+        )*
+    };
+}
+
+impl_ssz_pod!(u8);
+
+#[cfg(target_endian = "little")]
+impl_ssz_pod!(u16, u32, u64, u128);
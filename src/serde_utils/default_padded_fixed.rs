@@ -0,0 +1,89 @@
+//! Deserialize a `FixedVector<T, N>` from a list of at most `N` elements, padding any missing
+//! elements with `T::default()`.
+//!
+//! E.g., `[1, 2]` deserializes as `FixedVector::<u64, U4>::from(vec![1, 2, 0, 0])`. Serialization
+//! is unchanged from the `FixedVector` implementation, so all `N` elements are emitted.
+//!
+//! ## Note
+//!
+//! This relaxes the strict SSZ length semantics of `FixedVector`. It is intended for hand-edited
+//! configuration files and should not be used for consensus data.
+use crate::{list_type_name, FixedVector, VariableList};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::any::type_name;
+use typenum::Unsigned;
+
+pub fn serialize<S, T, N>(vec: &FixedVector<T, N>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    vec.serialize(serializer)
+}
+
+pub fn deserialize<'de, D, T, N>(deserializer: D) -> Result<FixedVector<T, N>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+    N: Unsigned,
+{
+    let mut vec = Vec::from(VariableList::<T, N>::deserialize(deserializer)?);
+    vec.resize_with(N::to_usize(), T::default);
+
+    FixedVector::new(vec).map_err(|e| {
+        serde::de::Error::custom(format!(
+            "invalid {}: {:?}",
+            list_type_name::<N>("FixedVector", type_name::<T>()),
+            e
+        ))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::FixedVector;
+    use serde_derive::{Deserialize, Serialize};
+    use typenum::{U0, U4};
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Obj {
+        #[serde(with = "crate::serde_utils::default_padded_fixed")]
+        values: FixedVector<u64, U4>,
+    }
+
+    #[test]
+    fn short_list_padded() {
+        let obj: Obj = serde_json::from_str(r#"{ "values": [1, 2] }"#).unwrap();
+        assert_eq!(obj.values, FixedVector::from(vec![1, 2, 0, 0]));
+        assert_eq!(
+            serde_json::to_string(&obj).unwrap(),
+            r#"{"values":[1,2,0,0]}"#
+        );
+    }
+
+    #[test]
+    fn empty_and_full_list_success() {
+        let obj: Obj = serde_json::from_str(r#"{ "values": [] }"#).unwrap();
+        assert_eq!(obj.values, FixedVector::from(vec![0; 4]));
+
+        let obj: Obj = serde_json::from_str(r#"{ "values": [1, 2, 3, 4] }"#).unwrap();
+        assert_eq!(obj.values, FixedVector::from(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn long_list_err() {
+        serde_json::from_str::<Obj>(r#"{ "values": [1, 2, 3, 4, 5] }"#).unwrap_err();
+    }
+
+    #[test]
+    fn zero_length_err() {
+        #[derive(Debug, Deserialize)]
+        struct Empty {
+            #[serde(with = "crate::serde_utils::default_padded_fixed")]
+            #[allow(dead_code)]
+            values: FixedVector<u64, U0>,
+        }
+
+        serde_json::from_str::<Empty>(r#"{ "values": [] }"#).unwrap_err();
+    }
+}
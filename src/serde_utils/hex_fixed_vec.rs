@@ -1,4 +1,4 @@
-use crate::FixedVector;
+use crate::{list_type_name, FixedVector};
 use serde::{Deserializer, Serializer};
 use serde_utils::hex::{self, PrefixedHexVisitor};
 use typenum::Unsigned;
@@ -17,6 +17,11 @@ where
     U: Unsigned,
 {
     let vec = deserializer.deserialize_string(PrefixedHexVisitor)?;
-    FixedVector::new(vec)
-        .map_err(|e| serde::de::Error::custom(format!("invalid fixed vector: {:?}", e)))
+    FixedVector::new(vec).map_err(|e| {
+        serde::de::Error::custom(format!(
+            "invalid {}: {:?}",
+            list_type_name::<U>("FixedVector", "u8"),
+            e
+        ))
+    })
 }
@@ -1,5 +1,5 @@
 //! Serialize `VariableList<u8, N>` as 0x-prefixed hex string.
-use crate::VariableList;
+use crate::{list_type_name, VariableList};
 use serde::{Deserializer, Serializer};
 use serde_utils::hex::{self, PrefixedHexVisitor};
 use typenum::Unsigned;
@@ -18,6 +18,11 @@ where
     N: Unsigned,
 {
     let bytes = deserializer.deserialize_str(PrefixedHexVisitor)?;
-    VariableList::new(bytes)
-        .map_err(|e| serde::de::Error::custom(format!("invalid variable list: {:?}", e)))
+    VariableList::new(bytes).map_err(|e| {
+        serde::de::Error::custom(format!(
+            "invalid {}: {:?}",
+            list_type_name::<N>("VariableList", "u8"),
+            e
+        ))
+    })
 }
@@ -0,0 +1,149 @@
+//! Serialize a sparse `FixedVector<T, N>` as a map from index to value.
+//!
+//! E.g., `FixedVector::from(vec![0, 5, 0, 7])` serializes as `{"1": 5, "3": 7}`. Elements equal to
+//! `T::default()` are omitted during encoding and any missing index is filled with `T::default()`
+//! during decoding.
+//!
+//! Deserialization fails if an index is not less than `N` or appears more than once.
+use crate::{list_type_name, FixedVector};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::any::type_name;
+use std::collections::HashSet;
+use std::marker::PhantomData;
+use typenum::Unsigned;
+
+pub fn serialize<S, T, N>(vec: &FixedVector<T, N>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize + Default + PartialEq,
+    N: Unsigned,
+{
+    let default = T::default();
+    let entries = vec
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| **value != default);
+
+    let mut map = serializer.serialize_map(Some(entries.clone().count()))?;
+    for (i, value) in entries {
+        map.serialize_entry(&i.to_string(), value)?;
+    }
+    map.end()
+}
+
+pub struct Visitor<T, N> {
+    _phantom: PhantomData<(T, N)>,
+}
+
+impl<'a, T, N> serde::de::Visitor<'a> for Visitor<T, N>
+where
+    T: Deserialize<'a> + Default,
+    N: Unsigned,
+{
+    type Value = FixedVector<T, N>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "a map of indices less than {} to values",
+            N::to_usize()
+        )
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'a>,
+    {
+        let mut vec: Vec<T> = std::iter::repeat_with(T::default)
+            .take(N::to_usize())
+            .collect();
+        let mut seen = HashSet::new();
+
+        while let Some(key) = map.next_key::<String>()? {
+            let i: usize = key
+                .parse()
+                .map_err(|e| serde::de::Error::custom(format!("invalid index {:?}: {}", key, e)))?;
+
+            if i >= vec.len() {
+                return Err(serde::de::Error::custom(format!(
+                    "index {} out of bounds for length {}",
+                    i,
+                    vec.len()
+                )));
+            }
+            if !seen.insert(i) {
+                return Err(serde::de::Error::custom(format!("duplicate index {}", i)));
+            }
+
+            vec[i] = map.next_value()?;
+        }
+
+        FixedVector::new(vec).map_err(|e| {
+            serde::de::Error::custom(format!(
+                "invalid {}: {:?}",
+                list_type_name::<N>("FixedVector", type_name::<T>()),
+                e
+            ))
+        })
+    }
+}
+
+pub fn deserialize<'de, D, T, N>(deserializer: D) -> Result<FixedVector<T, N>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+    N: Unsigned,
+{
+    deserializer.deserialize_map(Visitor {
+        _phantom: PhantomData,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::FixedVector;
+    use serde_derive::{Deserialize, Serialize};
+    use typenum::U4;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Obj {
+        #[serde(with = "crate::serde_utils::indexed_map")]
+        values: FixedVector<u64, U4>,
+    }
+
+    #[test]
+    fn sparse_round_trip() {
+        let obj: Obj = serde_json::from_str(r#"{ "values": { "1": 5, "3": 7 } }"#).unwrap();
+        assert_eq!(obj.values, FixedVector::from(vec![0, 5, 0, 7]));
+        assert_eq!(
+            serde_json::to_string(&obj).unwrap(),
+            r#"{"values":{"1":5,"3":7}}"#
+        );
+    }
+
+    #[test]
+    fn empty_map_is_default() {
+        let obj: Obj = serde_json::from_str(r#"{ "values": {} }"#).unwrap();
+        assert_eq!(obj.values, FixedVector::from(vec![0; 4]));
+        assert_eq!(serde_json::to_string(&obj).unwrap(), r#"{"values":{}}"#);
+    }
+
+    #[test]
+    fn out_of_bounds_index_err() {
+        let err = serde_json::from_str::<Obj>(r#"{ "values": { "4": 1 } }"#).unwrap_err();
+        assert!(err.to_string().contains("index 4 out of bounds"));
+    }
+
+    #[test]
+    fn duplicate_index_err() {
+        let err = serde_json::from_str::<Obj>(r#"{ "values": { "2": 1, "2": 3 } }"#).unwrap_err();
+        assert!(err.to_string().contains("duplicate index 2"));
+    }
+
+    #[test]
+    fn invalid_index_err() {
+        serde_json::from_str::<Obj>(r#"{ "values": { "-1": 1 } }"#).unwrap_err();
+        serde_json::from_str::<Obj>(r#"{ "values": { "one": 1 } }"#).unwrap_err();
+    }
+}
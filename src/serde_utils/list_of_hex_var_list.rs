@@ -1,5 +1,5 @@
 //! Serialize `VaraibleList<VariableList<u8, M>, N>` as list of 0x-prefixed hex string.
-use crate::VariableList;
+use crate::{list_type_name, VariableList};
 use serde::{ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer};
 use std::marker::PhantomData;
 use typenum::Unsigned;
@@ -57,7 +57,11 @@ where
 
         while let Some(val) = seq.next_element::<WrappedListOwned<M>>()? {
             list.push(val.0).map_err(|e| {
-                serde::de::Error::custom(format!("failed to push value to list: {:?}.", e))
+                serde::de::Error::custom(format!(
+                    "failed to push value to {}: {:?}.",
+                    list_type_name::<N>("VariableList", &list_type_name::<M>("VariableList", "u8")),
+                    e
+                ))
             })?;
         }
 
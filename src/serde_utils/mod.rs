@@ -1,6 +1,9 @@
+pub mod default_padded_fixed;
 pub mod hex_fixed_vec;
 pub mod hex_var_list;
+pub mod indexed_map;
 pub mod list_of_hex_fixed_vec;
 pub mod list_of_hex_var_list;
 pub mod quoted_u64_fixed_vec;
 pub mod quoted_u64_var_list;
+pub mod sorted_unique;
@@ -2,13 +2,25 @@
 //!
 //! E.g., `FixedVector::from(vec![0, 1, 2])` serializes as `["0", "1", "2"]`.
 //!
-//! Quotes can be optional during decoding. If `N` does not equal the length deserialization will fail.
+//! Integers are always quoted during encoding, but quotes can be optional during decoding. If `N` does not equal the length deserialization will fail.
+
+use crate::serde_utils::quoted_u64_var_list::deserialize_list;
+use crate::{list_type_name, FixedVector};
+use serde::Deserializer;
+use typenum::Unsigned;
 
 // The (de)serialisation functions for variable lists are now sufficiently general that we can
 // implement fixed vector (de)serialisation in terms of them.
-pub use crate::serde_utils::quoted_u64_var_list::deserialize;
 pub use crate::serde_utils::quoted_u64_var_list::serialize;
 
+pub fn deserialize<'de, D, N>(deserializer: D) -> Result<FixedVector<u64, N>, D::Error>
+where
+    D: Deserializer<'de>,
+    N: Unsigned,
+{
+    deserialize_list(deserializer, list_type_name::<N>("FixedVector", "u64"))
+}
+
 #[cfg(test)]
 mod test {
     use crate::FixedVector;
@@ -54,7 +66,25 @@ mod test {
 
     #[test]
     fn long_list_err() {
-        serde_json::from_str::<Obj>(r#"{ "values": [1, 2, 3, 4, 5] }"#).unwrap_err();
+        let err = serde_json::from_str::<Obj>(r#"{ "values": [1, 2, 3, 4, 5] }"#).unwrap_err();
+        assert!(
+            err.to_string().starts_with("FixedVector<u64, 4>: "),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn serialize_quoted() {
+        let obj = Obj {
+            values: FixedVector::from(vec![1, 2, 3, u64::MAX]),
+        };
+        let json = serde_json::to_string(&obj).unwrap();
+        assert_eq!(json, r#"{"values":["1","2","3","18446744073709551615"]}"#);
+        assert_eq!(
+            serde_json::from_str::<Obj>(&json).unwrap().values,
+            obj.values
+        );
     }
 
     #[test]
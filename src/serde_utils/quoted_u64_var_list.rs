@@ -2,8 +2,9 @@
 //!
 //! E.g., `VariableList::from(vec![0, 1, 2])` serializes as `["0", "1", "2"]`.
 //!
-//! Quotes can be optional during decoding. If the length of the `Vec` is greater than `N`, deserialization fails.
+//! Integers are always quoted during encoding, but quotes can be optional during decoding. If the length of the `Vec` is greater than `N`, deserialization fails.
 
+use crate::{list_type_name, VariableList};
 use itertools::process_results;
 use serde::ser::SerializeSeq;
 use serde::{de::Error, Deserializer, Serializer};
@@ -11,8 +12,11 @@ use serde_utils::quoted_u64_vec::QuotedIntWrapper;
 use ssz::TryFromIter;
 use std::iter;
 use std::marker::PhantomData;
+use typenum::Unsigned;
 
 pub struct QuotedIntVarListVisitor<C> {
+    /// The name of `C`, used in error messages.
+    name: String,
     _phantom: PhantomData<C>,
 }
 
@@ -32,7 +36,7 @@ where
     {
         process_results(iter::from_fn(|| seq.next_element().transpose()), |iter| {
             C::try_from_iter(iter.map(|QuotedIntWrapper { int }| int))
-                .map_err(|e| A::Error::custom(format!("{e:?}")))
+                .map_err(|e| A::Error::custom(format!("{}: {:?}", self.name, e)))
         })?
     }
 }
@@ -51,12 +55,23 @@ where
     seq.end()
 }
 
-pub fn deserialize<'de, D, C>(deserializer: D) -> Result<C, D::Error>
+pub fn deserialize<'de, D, N>(deserializer: D) -> Result<VariableList<u64, N>, D::Error>
+where
+    D: Deserializer<'de>,
+    N: Unsigned,
+{
+    deserialize_list(deserializer, list_type_name::<N>("VariableList", "u64"))
+}
+
+/// Deserializes a list of quoted or unquoted integers into `C`, naming it `name` in error
+/// messages.
+pub(crate) fn deserialize_list<'de, D, C>(deserializer: D, name: String) -> Result<C, D::Error>
 where
     D: Deserializer<'de>,
     C: TryFromIter<u64>,
 {
     deserializer.deserialize_any(QuotedIntVarListVisitor {
+        name,
         _phantom: PhantomData,
     })
 }
@@ -109,7 +124,25 @@ mod test {
 
     #[test]
     fn long_list_err() {
-        serde_json::from_str::<Obj>(r#"{ "values": [1, 2, 3, 4, 5] }"#).unwrap_err();
+        let err = serde_json::from_str::<Obj>(r#"{ "values": [1, 2, 3, 4, 5] }"#).unwrap_err();
+        assert!(
+            err.to_string().starts_with("VariableList<u64, 4>: "),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn serialize_quoted() {
+        let obj = Obj {
+            values: VariableList::from(vec![1, 2, 3, u64::MAX]),
+        };
+        let json = serde_json::to_string(&obj).unwrap();
+        assert_eq!(json, r#"{"values":["1","2","3","18446744073709551615"]}"#);
+        assert_eq!(
+            serde_json::from_str::<Obj>(&json).unwrap().values,
+            obj.values
+        );
     }
 
     #[test]
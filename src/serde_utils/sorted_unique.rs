@@ -0,0 +1,92 @@
+//! Deserialize a `VariableList<T, N>` whose elements must be strictly increasing.
+//!
+//! E.g., `[1, 2, 5]` deserializes successfully, whilst `[1, 5, 5]` and `[2, 1]` fail.
+//!
+//! Serialization is unchanged from the `VariableList` implementation.
+use crate::VariableList;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Debug;
+use typenum::Unsigned;
+
+pub fn serialize<S, T, N>(list: &VariableList<T, N>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    list.serialize(serializer)
+}
+
+pub fn deserialize<'de, D, T, N>(deserializer: D) -> Result<VariableList<T, N>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Ord + Debug,
+    N: Unsigned,
+{
+    let list = VariableList::<T, N>::deserialize(deserializer)?;
+
+    if let Some((i, pair)) = list
+        .windows(2)
+        .enumerate()
+        .find(|(_, pair)| pair[0] >= pair[1])
+    {
+        return Err(serde::de::Error::custom(format!(
+            "list is not strictly increasing: element {} ({:?}) is not less than element {} ({:?})",
+            i,
+            pair[0],
+            i + 1,
+            pair[1]
+        )));
+    }
+
+    Ok(list)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::VariableList;
+    use serde_derive::{Deserialize, Serialize};
+    use typenum::U4;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Obj {
+        #[serde(with = "crate::serde_utils::sorted_unique")]
+        values: VariableList<u64, U4>,
+    }
+
+    #[test]
+    fn sorted_success() {
+        let obj: Obj = serde_json::from_str(r#"{ "values": [1, 2, 5] }"#).unwrap();
+        assert_eq!(obj.values, VariableList::from(vec![1, 2, 5]));
+        assert_eq!(
+            serde_json::to_string(&obj).unwrap(),
+            r#"{"values":[1,2,5]}"#
+        );
+    }
+
+    #[test]
+    fn empty_and_single_success() {
+        serde_json::from_str::<Obj>(r#"{ "values": [] }"#).unwrap();
+        serde_json::from_str::<Obj>(r#"{ "values": [7] }"#).unwrap();
+    }
+
+    #[test]
+    fn duplicate_err() {
+        let err = serde_json::from_str::<Obj>(r#"{ "values": [1, 5, 5] }"#).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("element 1 (5) is not less than element 2 (5)"));
+    }
+
+    #[test]
+    fn unsorted_err() {
+        let err = serde_json::from_str::<Obj>(r#"{ "values": [2, 1] }"#).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("element 0 (2) is not less than element 1 (1)"));
+    }
+
+    #[test]
+    fn long_list_err() {
+        serde_json::from_str::<Obj>(r#"{ "values": [1, 2, 3, 4, 5] }"#).unwrap_err();
+    }
+}
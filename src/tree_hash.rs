@@ -1,28 +1,42 @@
+use crate::Error;
 use tree_hash::{Hash256, MerkleHasher, TreeHash, TreeHashType};
 use typenum::Unsigned;
 
 /// A helper function providing common functionality between the `TreeHash` implementations for
 /// `FixedVector` and `VariableList`.
 pub fn vec_tree_hash_root<T, N>(vec: &[T]) -> Hash256
+where
+    T: TreeHash,
+    N: Unsigned,
+{
+    let message = match T::tree_hash_type() {
+        TreeHashType::Basic => "ssz_types variable vec should not contain more elements than max",
+        TreeHashType::Container | TreeHashType::List | TreeHashType::Vector => {
+            "ssz_types vec should not contain more elements than max"
+        }
+    };
+    checked_vec_tree_hash_root::<T, N>(vec).expect(message)
+}
+
+/// As per `vec_tree_hash_root`, but returns an `Err` instead of panicking if `vec` contains more
+/// elements than the tree for `N` has leaves.
+pub fn checked_vec_tree_hash_root<T, N>(vec: &[T]) -> Result<Hash256, Error>
 where
     T: TreeHash,
     N: Unsigned,
 {
     match T::tree_hash_type() {
         TreeHashType::Basic => {
-            let mut hasher = MerkleHasher::with_leaves(
-                (N::to_usize() + T::tree_hash_packing_factor() - 1) / T::tree_hash_packing_factor(),
-            );
+            let mut hasher =
+                MerkleHasher::with_leaves(N::to_usize().div_ceil(T::tree_hash_packing_factor()));
 
             for item in vec {
                 hasher
                     .write(&item.tree_hash_packed_encoding())
-                    .expect("ssz_types variable vec should not contain more elements than max");
+                    .map_err(Error::TreeHashError)?;
             }
 
-            hasher
-                .finish()
-                .expect("ssz_types variable vec should not have a remaining buffer")
+            hasher.finish().map_err(Error::TreeHashError)
         }
         TreeHashType::Container | TreeHashType::List | TreeHashType::Vector => {
             let mut hasher = MerkleHasher::with_leaves(N::to_usize());
@@ -30,12 +44,10 @@ where
             for item in vec {
                 hasher
                     .write(item.tree_hash_root().as_slice())
-                    .expect("ssz_types vec should not contain more elements than max");
+                    .map_err(Error::TreeHashError)?;
             }
 
-            hasher
-                .finish()
-                .expect("ssz_types vec should not have a remaining buffer")
+            hasher.finish().map_err(Error::TreeHashError)
         }
     }
 }
@@ -1,9 +1,21 @@
-use crate::tree_hash::vec_tree_hash_root;
-use crate::Error;
-use serde_derive::{Deserialize, Serialize};
+use crate::bounded_decode::{
+    check_max_len, decode_list_with_budget, fixed_len_item_count, variable_len_item_count,
+    BoundedDecode,
+};
+use crate::tree_hash::{checked_vec_tree_hash_root, vec_tree_hash_root};
+use crate::{
+    checked_range, decode_hex, list_type_name, Error, FixedVector, HexError, TryFromResultError,
+};
+use serde::de::{Error as _, SeqAccess};
+use serde::{Deserialize, Deserializer};
+use serde_derive::Serialize;
+use smallvec::SmallVec;
+use std::any::type_name;
+use std::collections::TryReserveError;
 use std::marker::PhantomData;
-use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::ops::{Deref, DerefMut, Index, IndexMut, RangeBounds};
 use std::slice::SliceIndex;
+use std::sync::Arc;
 use tree_hash::Hash256;
 use typenum::Unsigned;
 
@@ -20,6 +32,11 @@ pub use typenum;
 /// The length of this struct is fixed at the type-level using
 /// [typenum](https://crates.io/crates/typenum).
 ///
+/// ## Note
+///
+/// A `VariableList<bool, N>` is an SSZ `List[boolean, N]`, which encodes each `bool` as a whole
+/// `0x00` or `0x01` byte. It is _not_ bit-packed; use `BitList` for an SSZ `Bitlist`.
+///
 /// ## Example
 ///
 /// ```
@@ -46,13 +63,27 @@ pub use typenum;
 /// // Push a value to if it _does_ exceed the maximum.
 /// assert!(long.push(6).is_err());
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Serialize)]
 #[serde(transparent)]
 pub struct VariableList<T, N> {
     vec: Vec<T>,
     _phantom: PhantomData<N>,
 }
 
+impl<T: Clone, N> Clone for VariableList<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            vec: self.vec.clone(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Reuses the existing allocation of `self`, where possible.
+    fn clone_from(&mut self, source: &Self) {
+        self.vec.clone_from(&source.vec);
+    }
+}
+
 // Implement comparison functions even if N doesn't implement PartialEq
 impl<T: PartialEq, N> PartialEq for VariableList<T, N> {
     fn eq(&self, other: &Self) -> bool {
@@ -60,9 +91,10 @@ impl<T: PartialEq, N> PartialEq for VariableList<T, N> {
     }
 }
 impl<T: Eq, N> Eq for VariableList<T, N> {}
+// Hash the contents only, so that lists which compare equal hash equally regardless of capacity.
 impl<T: std::hash::Hash, N> std::hash::Hash for VariableList<T, N> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.vec.hash(state);
+        self.vec.as_slice().hash(state);
     }
 }
 
@@ -74,6 +106,18 @@ impl<T: std::hash::Hash, N> std::hash::Hash for VariableList<T, N> {
 /// allocation around the 1MiB to 10MiB mark.
 const MAX_ELEMENTS_TO_PRE_ALLOCATE: usize = 128 * (1 << 10);
 
+/// Returns the number of elements to pre-allocate when building a list of maximum length `N` from
+/// an iterator with the given `size_hint`.
+///
+/// Trusts the upper bound of the size hint only up to `N`, clamped to
+/// `MAX_ELEMENTS_TO_PRE_ALLOCATE`.
+fn initial_capacity<N: Unsigned>(size_hint: (usize, Option<usize>)) -> usize {
+    let clamped_n = std::cmp::min(MAX_ELEMENTS_TO_PRE_ALLOCATE, N::to_usize());
+    size_hint
+        .1
+        .map_or(clamped_n, |max_len| std::cmp::min(clamped_n, max_len))
+}
+
 impl<T, N: Unsigned> VariableList<T, N> {
     /// Returns `Some` if the given `vec` equals the fixed length of `Self`. Otherwise returns
     /// `None`.
@@ -114,6 +158,22 @@ impl<T, N: Unsigned> VariableList<T, N> {
         N::to_usize()
     }
 
+    /// Returns the type-level length bound `N`, as per `max_len`.
+    ///
+    /// Named identically on `FixedVector` and `VariableList` for use in code which is generic
+    /// over both.
+    pub const fn type_len() -> usize {
+        N::USIZE
+    }
+
+    /// Returns the approximate number of heap bytes held by `self`: the allocated capacity of the
+    /// backing `Vec` multiplied by `size_of::<T>()`.
+    ///
+    /// Heap data owned by the elements themselves (e.g., nested lists) is not included.
+    pub fn mem_usage(&self) -> usize {
+        self.vec.capacity() * std::mem::size_of::<T>()
+    }
+
     /// Appends `value` to the back of `self`.
     ///
     /// Returns `Err(())` when appending `value` would exceed the maximum length.
@@ -128,6 +188,344 @@ impl<T, N: Unsigned> VariableList<T, N> {
             })
         }
     }
+
+    /// Clones and appends all elements of `src` to the back of `self`.
+    ///
+    /// Returns `Err` and leaves `self` unchanged if appending `src` would exceed the maximum
+    /// length; no elements are appended in that case.
+    pub fn try_extend_from_slice(&mut self, src: &[T]) -> Result<(), Error>
+    where
+        T: Clone,
+    {
+        let new_len = self.len().saturating_add(src.len());
+        if new_len > Self::max_len() {
+            return Err(Error::OutOfBounds {
+                i: new_len,
+                len: Self::max_len(),
+            });
+        }
+        self.vec.extend_from_slice(src);
+        Ok(())
+    }
+
+    /// Calls `f` with mutable access to the backing `Vec`, for edits which the slice API cannot
+    /// express, then checks that `self` is still within the maximum length.
+    ///
+    /// If `f` leaves more than `N` elements, the excess elements are dropped (as per
+    /// `From<Vec<T>>`) and `Error::OutOfBounds` is returned, so `self` always remains valid.
+    pub fn with_vec_mut<R, F>(&mut self, f: F) -> Result<R, Error>
+    where
+        F: FnOnce(&mut Vec<T>) -> R,
+    {
+        let result = f(&mut self.vec);
+
+        let len = self.vec.len();
+        if len > Self::max_len() {
+            self.vec.truncate(Self::max_len());
+            return Err(Error::OutOfBounds {
+                i: len,
+                len: Self::max_len(),
+            });
+        }
+        Ok(result)
+    }
+
+    /// Returns the elements of `self` within `range`, or an `Err` rather than panicking if `range`
+    /// is out of bounds.
+    ///
+    /// Returns `Error::OutOfBounds { i: end, len }` if `range` ends past the end of `self`, or
+    /// `Error::InvalidRange { start, end }` if `range` starts after it ends.
+    pub fn try_slice<R: RangeBounds<usize>>(&self, range: R) -> Result<&[T], Error> {
+        Ok(&self.vec[checked_range(range, self.len())?])
+    }
+
+    /// Returns a mutable reference to the first element, or `None` if `self` is empty.
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.vec.first_mut()
+    }
+
+    /// Returns a mutable reference to the last element, or `None` if `self` is empty.
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.vec.last_mut()
+    }
+
+    /// Returns `true` if `iter` yields exactly the elements of `self`, in order.
+    ///
+    /// Avoids collecting `iter` just to compare it.
+    pub fn eq_iter<I>(&self, iter: I) -> bool
+    where
+        T: PartialEq,
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+        self.vec
+            .iter()
+            .all(|item| iter.next().is_some_and(|other| *item == other))
+            && iter.next().is_none()
+    }
+
+    /// Returns an iterator over the elements of `self`, from last to first.
+    pub fn iter_rev(&self) -> std::iter::Rev<std::slice::Iter<'_, T>> {
+        self.vec.iter().rev()
+    }
+
+    /// Returns an owning iterator over the elements of `self`, from last to first.
+    pub fn into_iter_rev(self) -> std::iter::Rev<std::vec::IntoIter<T>> {
+        self.vec.into_iter().rev()
+    }
+
+    /// Converts `self` into a boxed slice, dropping any excess capacity.
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        self.vec.into_boxed_slice()
+    }
+
+    /// Converts `self` into a reference-counted slice, for sharing without cloning the elements.
+    pub fn into_arc_slice(self) -> Arc<[T]> {
+        Arc::from(self.vec)
+    }
+
+    /// Returns `true` if the elements of `self` are sorted in ascending order.
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.vec.is_sorted()
+    }
+
+    /// Returns `true` if `compare` returns `true` for every pair of consecutive elements.
+    pub fn is_sorted_by<F>(&self, compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.vec.is_sorted_by(compare)
+    }
+
+    /// Returns `true` if the keys extracted by `f` are sorted in ascending order.
+    pub fn is_sorted_by_key<K, F>(&self, f: F) -> bool
+    where
+        F: FnMut(&T) -> K,
+        K: PartialOrd,
+    {
+        self.vec.is_sorted_by_key(f)
+    }
+
+    /// Returns the sum of all elements in `self`.
+    pub fn sum(&self) -> T
+    where
+        T: Copy + std::iter::Sum,
+    {
+        self.vec.iter().copied().sum()
+    }
+
+    /// Returns the greatest element in `self`, or `None` if `self` is empty.
+    pub fn max(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.vec.iter().max()
+    }
+
+    /// Returns the least element in `self`, or `None` if `self` is empty.
+    pub fn min(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.vec.iter().min()
+    }
+
+    /// Returns the first element and the rest of `self`, or `None` if `self` is empty.
+    pub fn split_first(&self) -> Option<(&T, &[T])> {
+        self.vec.split_first()
+    }
+
+    /// Removes the first element of `self`, returning it along with the remaining list.
+    ///
+    /// Returns `None` if `self` is empty. The remainder is always within the maximum length since
+    /// it is shorter than `self`.
+    ///
+    /// This shifts every remaining element down by one, taking O(n) time, so splitting off the
+    /// head of a list repeatedly is quadratic. To process every element in order, use `into_iter`
+    /// instead.
+    pub fn into_split_first(mut self) -> Option<(T, Self)> {
+        if self.vec.is_empty() {
+            None
+        } else {
+            let first = self.vec.remove(0);
+            Some((first, self))
+        }
+    }
+
+    /// Removes the elements in `range` from `self`, returning them as an iterator.
+    ///
+    /// See `Vec::drain` for details. Removing elements can never violate the maximum length.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    pub fn drain<R>(&mut self, range: R) -> std::vec::Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.vec.drain(range)
+    }
+
+    /// Returns the index of the first element for which `pred` returns `false`, assuming `self`
+    /// is partitioned according to `pred`.
+    ///
+    /// For a sorted list, this is the index at which a value can be inserted whilst keeping the
+    /// list sorted.
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.vec.partition_point(pred)
+    }
+
+    /// Returns an iterator over mutable chunks of `self`, each `chunk_size` long except possibly
+    /// the last.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> std::slice::ChunksMut<'_, T> {
+        self.vec.chunks_mut(chunk_size)
+    }
+
+    /// Calls `f` on each mutable chunk of `self`, as per `Self::chunks_mut`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn for_each_chunk_mut<F>(&mut self, chunk_size: usize, f: F)
+    where
+        F: FnMut(&mut [T]),
+    {
+        self.chunks_mut(chunk_size).for_each(f)
+    }
+
+    /// Tries to reserve capacity for `additional` more elements, clamped to the room remaining
+    /// before the maximum length.
+    ///
+    /// Returns an `Err` rather than aborting if the allocation fails.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let room = Self::max_len().saturating_sub(self.len());
+        self.vec.try_reserve(std::cmp::min(additional, room))
+    }
+
+    /// As per `TryFromIter::try_from_iter`, but returns `Error::AllocationFailed` rather than
+    /// aborting if an allocation fails.
+    pub fn try_from_iter_fallible<I>(iter: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+
+        // Pre-allocate up to `N` elements based on the iterator size hint.
+        let mut l = Self::empty();
+        l.try_reserve(initial_capacity::<N>(iter.size_hint()))
+            .map_err(Error::AllocationFailed)?;
+        for item in iter {
+            if l.vec.len() == l.vec.capacity() {
+                l.try_reserve(1).map_err(Error::AllocationFailed)?;
+            }
+            l.push(item)?;
+        }
+        Ok(l)
+    }
+
+    /// Builds a list from an iterator of `Result`s, returning an `Err` as soon as an element is
+    /// an `Err` or the list would exceed the maximum length.
+    pub fn try_from_result_iter<E, I>(iter: I) -> Result<Self, TryFromResultError<E>>
+    where
+        I: IntoIterator<Item = Result<T, E>>,
+    {
+        let iter = iter.into_iter();
+
+        // Pre-allocate up to `N` elements based on the iterator size hint.
+        let mut l = Self::new(Vec::with_capacity(initial_capacity::<N>(iter.size_hint())))
+            .map_err(TryFromResultError::List)?;
+        for item in iter {
+            l.push(item.map_err(TryFromResultError::Element)?)
+                .map_err(TryFromResultError::List)?;
+        }
+        Ok(l)
+    }
+
+    /// Returns the tree hash root of `self`.
+    ///
+    /// Unlike `TreeHash::tree_hash_root`, returns an `Err` rather than panicking if hashing fails.
+    pub fn checked_tree_hash_root(&self) -> Result<Hash256, Error>
+    where
+        T: tree_hash::TreeHash,
+    {
+        let root = checked_vec_tree_hash_root::<T, N>(&self.vec)?;
+
+        Ok(tree_hash::mix_in_length(&root, self.len()))
+    }
+}
+
+/// Concatenates the inner lists of `nested` into a single list with maximum length `P`.
+///
+/// Returns `Err` if the total number of elements exceeds `P`.
+pub fn flatten<T, M, N, P>(
+    nested: VariableList<VariableList<T, M>, N>,
+) -> Result<VariableList<T, P>, Error>
+where
+    M: Unsigned,
+    N: Unsigned,
+    P: Unsigned,
+{
+    let total = nested.iter().map(|inner| inner.len()).sum();
+    if total > P::to_usize() {
+        return Err(Error::OutOfBounds {
+            i: total,
+            len: P::to_usize(),
+        });
+    }
+
+    let mut vec = Vec::with_capacity(total);
+    for inner in nested {
+        vec.extend(inner);
+    }
+    VariableList::new(vec)
+}
+
+/// Groups the items of `iter` into consecutive `FixedVector`s of length `C`, collecting them into
+/// a list with maximum length `N`.
+///
+/// Returns `Error::InvalidChunkLength { len, chunk_len: C }` if the number of items `len` is not
+/// a multiple of `C`. If `C` is zero, this is returned with `len: 0` without consuming `iter`.
+///
+/// Returns `Error::OutOfBounds` if there are more than `N` groups.
+pub fn collect_chunked<T, C, N, I>(iter: I) -> Result<VariableList<FixedVector<T, C>, N>, Error>
+where
+    C: Unsigned,
+    N: Unsigned,
+    I: IntoIterator<Item = T>,
+{
+    let chunk_len = C::to_usize();
+    if chunk_len == 0 {
+        return Err(Error::InvalidChunkLength { len: 0, chunk_len });
+    }
+
+    let mut list = VariableList::empty();
+    let mut chunk = Vec::with_capacity(chunk_len);
+    for item in iter {
+        chunk.push(item);
+        if chunk.len() == chunk_len {
+            let full = std::mem::replace(&mut chunk, Vec::with_capacity(chunk_len));
+            list.push(FixedVector::new(full)?)?;
+        }
+    }
+
+    if chunk.is_empty() {
+        Ok(list)
+    } else {
+        Err(Error::InvalidChunkLength {
+            len: list.len() * chunk_len + chunk.len(),
+            chunk_len,
+        })
+    }
 }
 
 impl<T, N: Unsigned> From<Vec<T>> for VariableList<T, N> {
@@ -141,12 +539,49 @@ impl<T, N: Unsigned> From<Vec<T>> for VariableList<T, N> {
     }
 }
 
+impl<N: Unsigned> std::str::FromStr for VariableList<u8, N> {
+    type Err = HexError;
+
+    /// Parses a hex string, with or without a `0x` prefix.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(decode_hex(s)?).map_err(HexError::InvalidLength)
+    }
+}
+
+impl<A: smallvec::Array, N: Unsigned> TryFrom<SmallVec<A>> for VariableList<A::Item, N> {
+    type Error = Error;
+
+    /// Checks the length of `smallvec` before moving its elements into a `Vec`, which only
+    /// allocates if `smallvec` has not already spilled onto the heap.
+    fn try_from(smallvec: SmallVec<A>) -> Result<Self, Self::Error> {
+        if smallvec.len() > Self::max_len() {
+            return Err(Error::OutOfBounds {
+                i: smallvec.len(),
+                len: Self::max_len(),
+            });
+        }
+        Self::new(smallvec.into_vec())
+    }
+}
+
 impl<T, N: Unsigned> From<VariableList<T, N>> for Vec<T> {
     fn from(list: VariableList<T, N>) -> Vec<T> {
         list.vec
     }
 }
 
+impl<T, N: Unsigned> From<VariableList<T, N>> for Box<[T]> {
+    fn from(list: VariableList<T, N>) -> Box<[T]> {
+        list.into_boxed_slice()
+    }
+}
+
+impl<T, N: Unsigned> From<VariableList<T, N>> for Arc<[T]> {
+    fn from(list: VariableList<T, N>) -> Arc<[T]> {
+        list.into_arc_slice()
+    }
+}
+
 impl<T, N: Unsigned> Default for VariableList<T, N> {
     fn default() -> Self {
         Self {
@@ -156,19 +591,39 @@ impl<T, N: Unsigned> Default for VariableList<T, N> {
     }
 }
 
-impl<T, N: Unsigned, I: SliceIndex<[T]>> Index<I> for VariableList<T, N> {
+#[cold]
+#[inline(never)]
+fn index_out_of_bounds<N: Unsigned>(index: impl std::fmt::Debug, len: usize) -> ! {
+    panic!(
+        "VariableList<.., {}> index {:?} out of bounds (len {})",
+        N::to_usize(),
+        index,
+        len
+    )
+}
+
+impl<T, N: Unsigned, I: SliceIndex<[T]> + Clone + std::fmt::Debug> Index<I> for VariableList<T, N> {
     type Output = I::Output;
 
     #[inline]
     fn index(&self, index: I) -> &Self::Output {
-        Index::index(&self.vec, index)
+        match self.vec.get(index.clone()) {
+            Some(output) => output,
+            None => index_out_of_bounds::<N>(index, self.len()),
+        }
     }
 }
 
-impl<T, N: Unsigned, I: SliceIndex<[T]>> IndexMut<I> for VariableList<T, N> {
+impl<T, N: Unsigned, I: SliceIndex<[T]> + Clone + std::fmt::Debug> IndexMut<I>
+    for VariableList<T, N>
+{
     #[inline]
     fn index_mut(&mut self, index: I) -> &mut Self::Output {
-        IndexMut::index_mut(&mut self.vec, index)
+        let len = self.len();
+        match self.vec.get_mut(index.clone()) {
+            Some(output) => output,
+            None => index_out_of_bounds::<N>(index, len),
+        }
     }
 }
 
@@ -204,6 +659,67 @@ impl<T, N: Unsigned> IntoIterator for VariableList<T, N> {
     }
 }
 
+struct VariableListVisitor<T, N> {
+    _phantom: PhantomData<(T, N)>,
+}
+
+impl<'de, T, N> serde::de::Visitor<'de> for VariableListVisitor<T, N>
+where
+    T: Deserialize<'de>,
+    N: Unsigned,
+{
+    type Value = VariableList<T, N>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "a list of at most {} elements for {}",
+            N::to_usize(),
+            list_type_name::<N>("VariableList", type_name::<T>())
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let max_len = N::to_usize();
+        let clamped_n = std::cmp::min(MAX_ELEMENTS_TO_PRE_ALLOCATE, max_len);
+
+        // Don't trust the size hint beyond `N`, it may come from an untrusted length prefix.
+        let mut vec = Vec::with_capacity(seq.size_hint().map_or(0, |hint| hint.min(clamped_n)));
+
+        while let Some(item) = seq.next_element()? {
+            // Bail out as soon as the length tries to exceed the limit, rather than
+            // deserializing the remainder of the sequence.
+            if vec.len() >= max_len {
+                return Err(A::Error::invalid_length(max_len + 1, &self));
+            }
+            vec.push(item);
+        }
+
+        Ok(VariableList {
+            vec,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'de, T, N> Deserialize<'de> for VariableList<T, N>
+where
+    T: Deserialize<'de>,
+    N: Unsigned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(VariableListVisitor {
+            _phantom: PhantomData,
+        })
+    }
+}
+
 impl<T, N: Unsigned> tree_hash::TreeHash for VariableList<T, N>
 where
     T: tree_hash::TreeHash,
@@ -248,6 +764,22 @@ where
     }
 }
 
+impl<T, N: Unsigned> VariableList<T, N>
+where
+    T: ssz::Encode,
+{
+    /// Appends the SSZ encoding of `self` to `buf`, as per `ssz::Encode::ssz_append`, and returns
+    /// the number of bytes appended.
+    ///
+    /// This avoids calling `ssz_bytes_len` separately, which visits every element again when `T`
+    /// is variable-length.
+    pub fn ssz_append_returning_len(&self, buf: &mut Vec<u8>) -> usize {
+        let start = buf.len();
+        ssz::Encode::ssz_append(self, buf);
+        buf.len() - start
+    }
+}
+
 impl<T, N: Unsigned> ssz::TryFromIter<T> for VariableList<T, N> {
     type Error = Error;
 
@@ -255,15 +787,10 @@ impl<T, N: Unsigned> ssz::TryFromIter<T> for VariableList<T, N> {
     where
         I: IntoIterator<Item = T>,
     {
-        let n = N::to_usize();
-        let clamped_n = std::cmp::min(MAX_ELEMENTS_TO_PRE_ALLOCATE, n);
         let iter = value.into_iter();
 
         // Pre-allocate up to `N` elements based on the iterator size hint.
-        let (_, opt_max_len) = iter.size_hint();
-        let mut l = Self::new(Vec::with_capacity(
-            opt_max_len.map_or(clamped_n, |max_len| std::cmp::min(clamped_n, max_len)),
-        ))?;
+        let mut l = Self::new(Vec::with_capacity(initial_capacity::<N>(iter.size_hint())))?;
         for item in iter {
             l.push(item)?;
         }
@@ -286,17 +813,12 @@ where
         if bytes.is_empty() {
             Ok(vec![].into())
         } else if T::is_ssz_fixed_len() {
-            let num_items = bytes
-                .len()
-                .checked_div(T::ssz_fixed_len())
-                .ok_or(ssz::DecodeError::ZeroLengthItem)?;
-
-            if num_items > max_len {
-                return Err(ssz::DecodeError::BytesInvalid(format!(
-                    "VariableList of {} items exceeds maximum of {}",
-                    num_items, max_len
-                )));
-            }
+            let num_items = fixed_len_item_count::<T>(bytes)?;
+            check_max_len(
+                num_items,
+                max_len,
+                &list_type_name::<N>("VariableList", type_name::<T>()),
+            )?;
 
             bytes
                 .chunks(T::ssz_fixed_len())
@@ -306,32 +828,216 @@ where
                 })
                 .map(Into::into)
         } else {
+            // The first offset determines the number of items. Check it against the maximum
+            // before decoding any items so a crafted offset table can't trigger a large
+            // allocation.
+            variable_len_item_count(
+                bytes,
+                max_len,
+                &list_type_name::<N>("VariableList", type_name::<T>()),
+            )?;
+
             ssz::decode_list_of_variable_length_items(bytes, Some(max_len))
                 .map(|vec: Vec<_>| vec.into())
         }
     }
 }
 
-#[cfg(feature = "arbitrary")]
-impl<'a, T: arbitrary::Arbitrary<'a>, N: 'static + Unsigned> arbitrary::Arbitrary<'a>
-    for VariableList<T, N>
+impl<T, N> VariableList<T, N>
+where
+    T: ssz::Decode,
+    N: Unsigned,
 {
-    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
-        let max_size = N::to_usize();
-        let rand = usize::arbitrary(u)?;
-        let size = std::cmp::min(rand, max_size);
-        let mut vec: Vec<T> = Vec::with_capacity(size);
-        for _ in 0..size {
-            vec.push(<T>::arbitrary(u)?);
+    /// Decodes `bytes` as per `ssz::Decode::from_ssz_bytes`, where the caller already knows that
+    /// the list has `count` items.
+    ///
+    /// Returns `Err` if `count` exceeds `N` or if `bytes` does not encode exactly `count` items:
+    /// for fixed-length `T`, `bytes.len()` must equal `count * T::ssz_fixed_len()`.
+    pub fn from_ssz_bytes_with_count(bytes: &[u8], count: usize) -> Result<Self, ssz::DecodeError> {
+        let max_len = N::to_usize();
+
+        check_max_len(
+            count,
+            max_len,
+            &list_type_name::<N>("VariableList", type_name::<T>()),
+        )?;
+
+        if count == 0 && bytes.is_empty() {
+            Ok(vec![].into())
+        } else if T::is_ssz_fixed_len() {
+            if T::ssz_fixed_len() == 0 {
+                return Err(ssz::DecodeError::ZeroLengthItem);
+            }
+
+            // `count <= N`, so this can only overflow for absurdly large `N`.
+            let expected = count.saturating_mul(T::ssz_fixed_len());
+            if bytes.len() != expected {
+                return Err(ssz::DecodeError::InvalidByteLength {
+                    len: bytes.len(),
+                    expected,
+                });
+            }
+
+            let mut vec = Vec::with_capacity(count);
+            for chunk in bytes.chunks_exact(T::ssz_fixed_len()) {
+                vec.push(T::from_ssz_bytes(chunk)?);
+            }
+            Ok(vec.into())
+        } else {
+            let num_items = ssz::read_offset(bytes)? / ssz::BYTES_PER_LENGTH_OFFSET;
+
+            if num_items != count {
+                return Err(ssz::DecodeError::BytesInvalid(format!(
+                    "{}: {} items does not match expected count {}",
+                    list_type_name::<N>("VariableList", type_name::<T>()),
+                    num_items,
+                    count
+                )));
+            }
+
+            <Self as ssz::Decode>::from_ssz_bytes(bytes)
         }
-        Ok(Self::new(vec).map_err(|_| arbitrary::Error::IncorrectFormat)?)
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use ssz::*;
+impl<T, N> BoundedDecode for VariableList<T, N>
+where
+    T: BoundedDecode,
+    N: Unsigned,
+{
+    fn from_ssz_bytes_with_budget(
+        bytes: &[u8],
+        budget: &mut usize,
+    ) -> Result<Self, ssz::DecodeError> {
+        decode_list_with_budget(
+            bytes,
+            N::to_usize(),
+            budget,
+            &list_type_name::<N>("VariableList", type_name::<T>()),
+        )
+        .map(|vec: Vec<_>| vec.into())
+    }
+}
+
+impl<T, N> VariableList<T, N>
+where
+    T: BoundedDecode,
+    N: Unsigned,
+{
+    /// Decodes `bytes` as per `ssz::Decode::from_ssz_bytes`, but returns an `Err` if more than
+    /// `total_element_budget` elements would be decoded across this list and any nested
+    /// `VariableList` or `FixedVector`.
+    pub fn from_ssz_bytes_bounded(
+        bytes: &[u8],
+        mut total_element_budget: usize,
+    ) -> Result<Self, ssz::DecodeError> {
+        Self::from_ssz_bytes_with_budget(bytes, &mut total_element_budget)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<N: Unsigned> VariableList<u8, N> {
+    /// Builds a list from `bytes`, which must not be longer than `N`.
+    ///
+    /// The length is checked before conversion. The buffer is reused without copying if `bytes`
+    /// is the only reference to it.
+    pub fn from_bytes_buf(bytes: bytes::Bytes) -> Result<Self, Error> {
+        if bytes.len() > Self::max_len() {
+            return Err(Error::OutOfBounds {
+                i: bytes.len(),
+                len: Self::max_len(),
+            });
+        }
+        Self::new(bytes.into())
+    }
+
+    /// Converts `self` into a reference-counted `Bytes`, without copying.
+    pub fn into_bytes(self) -> bytes::Bytes {
+        self.vec.into()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: Send, N: Unsigned> VariableList<T, N> {
+    /// Builds a list from a parallel iterator which must yield at most `N` items.
+    ///
+    /// If the parallel iterator has a known length, it is checked before any items are computed.
+    /// Otherwise, all items are collected before the length is checked.
+    pub fn try_from_par_iter<I>(iter: I) -> Result<Self, Error>
+    where
+        I: rayon::iter::IntoParallelIterator<Item = T>,
+    {
+        use rayon::iter::ParallelIterator;
+
+        let iter = iter.into_par_iter();
+        if let Some(len) = iter.opt_len().filter(|&len| len > N::to_usize()) {
+            return Err(Error::OutOfBounds {
+                i: len,
+                len: N::to_usize(),
+            });
+        }
+        Self::new(iter.collect())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>, N: 'static + Unsigned> arbitrary::Arbitrary<'a>
+    for VariableList<T, N>
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // Derive the length from the remaining input, rather than a uniform `usize`, so that short
+        // lists are generated as often as long ones.
+        let size = std::cmp::min(u.arbitrary_len::<T>()?, N::to_usize());
+        let mut vec: Vec<T> = Vec::with_capacity(size);
+        for _ in 0..size {
+            vec.push(<T>::arbitrary(u)?);
+        }
+        Self::new(vec).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<T, N> VariableList<T, N>
+where
+    T: std::fmt::Debug,
+    N: std::fmt::Debug + Unsigned,
+{
+    /// Returns a `proptest` strategy which generates lists of `0..=N` elements drawn from
+    /// `element`.
+    pub fn strategy<S>(element: S) -> impl proptest::strategy::Strategy<Value = Self>
+    where
+        S: proptest::strategy::Strategy<Value = T>,
+    {
+        use proptest::strategy::Strategy;
+
+        proptest::collection::vec(element, 0..=N::to_usize()).prop_map(|vec| Self {
+            vec,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<T, N> proptest::arbitrary::Arbitrary for VariableList<T, N>
+where
+    T: proptest::arbitrary::Arbitrary + 'static,
+    N: std::fmt::Debug + Unsigned + 'static,
+{
+    type Parameters = T::Parameters;
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        Self::strategy(proptest::arbitrary::any_with::<T>(args)).boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::FixedVector;
+    use ssz::*;
     use std::collections::HashSet;
     use tree_hash::{merkle_root, TreeHash};
     use tree_hash_derive::TreeHash;
@@ -352,6 +1058,167 @@ mod test {
         assert!(fixed.is_ok());
     }
 
+    #[test]
+    fn out_of_bounds_lengths() {
+        type List = VariableList<u64, U4>;
+
+        assert_eq!(
+            List::new(vec![0; 6]),
+            Err(Error::OutOfBounds { i: 6, len: 4 })
+        );
+        assert_eq!(
+            List::try_from_iter(0..),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+
+        let mut list = List::new(vec![0; 4]).unwrap();
+        assert_eq!(list.push(0), Err(Error::OutOfBounds { i: 5, len: 4 }));
+    }
+
+    #[test]
+    fn try_extend_from_slice() {
+        let mut list: VariableList<u64, U4> = vec![1, 2].into();
+        assert_eq!(list.try_extend_from_slice(&[3]), Ok(()));
+        assert_eq!(list.try_extend_from_slice(&[]), Ok(()));
+        assert_eq!(&list[..], &[1, 2, 3]);
+
+        // Overflowing by one leaves `list` untouched.
+        let capacity = list.vec.capacity();
+        assert_eq!(
+            list.try_extend_from_slice(&[4, 5]),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+        assert_eq!(&list[..], &[1, 2, 3]);
+        assert_eq!(list.vec.capacity(), capacity);
+
+        assert_eq!(list.try_extend_from_slice(&[4]), Ok(()));
+        assert_eq!(&list[..], &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn try_reserve() {
+        let mut list: VariableList<u64, U4> = vec![1].into();
+        assert_eq!(list.try_reserve(usize::MAX), Ok(()));
+        assert!(list.vec.capacity() >= 4);
+
+        // Reserving `N` elements of 16 MiB each exceeds `isize::MAX` bytes.
+        let mut list: VariableList<[u8; 1 << 24], U1099511627776> = VariableList::empty();
+        assert!(list.try_reserve(usize::MAX).is_err());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn try_from_iter_fallible() {
+        type List = VariableList<u64, U4>;
+
+        assert_eq!(
+            List::try_from_iter_fallible(0..3),
+            Ok(List::from(vec![0, 1, 2]))
+        );
+        assert_eq!(
+            List::try_from_iter_fallible((0..).take_while(|x| *x < 4)),
+            Ok(List::from(vec![0, 1, 2, 3]))
+        );
+        assert_eq!(
+            List::try_from_iter_fallible(0..),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+    }
+
+    #[test]
+    fn collect_chunked() {
+        let list = super::collect_chunked::<_, U2, U3, _>(1..=6).unwrap();
+        assert_eq!(
+            list.iter().map(|chunk| chunk.to_vec()).collect::<Vec<_>>(),
+            vec![vec![1, 2], vec![3, 4], vec![5, 6]]
+        );
+        assert!(super::collect_chunked::<u64, U2, U3, _>(vec![])
+            .unwrap()
+            .is_empty());
+
+        assert_eq!(
+            super::collect_chunked::<_, U2, U3, _>(1..=5),
+            Err(Error::InvalidChunkLength {
+                len: 5,
+                chunk_len: 2
+            })
+        );
+        assert_eq!(
+            super::collect_chunked::<_, U2, U3, _>(1..),
+            Err(Error::OutOfBounds { i: 4, len: 3 })
+        );
+        assert_eq!(
+            super::collect_chunked::<_, U0, U3, _>(1..=2),
+            Err(Error::InvalidChunkLength {
+                len: 0,
+                chunk_len: 0
+            })
+        );
+    }
+
+    #[test]
+    fn with_vec_mut() {
+        let mut list: VariableList<u64, U4> = vec![1, 2, 3].into();
+        assert_eq!(list.with_vec_mut(|vec| vec.retain(|x| x % 2 == 1)), Ok(()));
+        assert_eq!(&list[..], &[1, 3]);
+
+        assert_eq!(
+            list.with_vec_mut(|vec| {
+                vec.extend([5, 7, 9]);
+                vec.len()
+            }),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+        assert_eq!(&list[..], &[1, 3, 5, 7]);
+    }
+
+    #[test]
+    fn into_boxed_slice() {
+        let mut list: VariableList<u64, U1024> = VariableList::new(Vec::with_capacity(64)).unwrap();
+        list.push(1).unwrap();
+        list.push(2).unwrap();
+
+        let boxed: Box<[u64]> = list.clone().into();
+        assert_eq!(&boxed[..], &[1, 2]);
+        assert_eq!(list.into_boxed_slice(), boxed);
+    }
+
+    #[test]
+    fn into_arc_slice() {
+        let list: VariableList<u64, U8> = vec![1, 2, 3].into();
+        let arc: Arc<[u64]> = list.clone().into();
+        assert_eq!(&arc[..], &[1, 2, 3]);
+        assert_eq!(list.into_arc_slice(), arc);
+    }
+
+    #[test]
+    fn try_slice() {
+        let list: VariableList<u64, U8> = vec![1, 2, 3].into();
+        assert_eq!(list.try_slice(1..), Ok(&[2, 3][..]));
+        assert_eq!(
+            list.try_slice(..4),
+            Err(Error::OutOfBounds { i: 4, len: 3 })
+        );
+        let (start, end) = (2, 1);
+        assert_eq!(
+            list.try_slice(start..end),
+            Err(Error::InvalidRange { start: 2, end: 1 })
+        );
+    }
+
+    #[test]
+    fn is_sorted() {
+        let list: VariableList<u64, U8> = vec![1, 2, 2, 5].into();
+        assert!(list.is_sorted());
+        assert!(!list.is_sorted_by(|a, b| a < b));
+        assert!(!list.is_sorted_by_key(|x| std::cmp::Reverse(*x)));
+        assert!(VariableList::<u64, U8>::empty().is_sorted());
+
+        let list: VariableList<u64, U8> = vec![3, 1].into();
+        assert!(!list.is_sorted());
+        assert!(list.is_sorted_by_key(|x| std::cmp::Reverse(*x)));
+    }
+
     #[test]
     fn indexing() {
         let vec = vec![1, 2];
@@ -366,6 +1233,20 @@ mod test {
         assert_eq!(fixed[1], 3);
     }
 
+    #[test]
+    #[should_panic(expected = "VariableList<.., 4> index 5 out of bounds (len 3)")]
+    fn index_out_of_bounds() {
+        let list: VariableList<u64, U4> = VariableList::from(vec![1, 2, 3]);
+        let _ = list[5];
+    }
+
+    #[test]
+    #[should_panic(expected = "VariableList<.., 4> index 1..5 out of bounds (len 3)")]
+    fn index_mut_range_out_of_bounds() {
+        let mut list: VariableList<u64, U4> = VariableList::from(vec![1, 2, 3]);
+        list[1..5].fill(0);
+    }
+
     #[test]
     fn length() {
         let vec = vec![42; 5];
@@ -392,6 +1273,231 @@ mod test {
         assert_eq!(fixed.get(4), None);
     }
 
+    #[test]
+    fn first_last_mut() {
+        let mut list: VariableList<u64, U4> = VariableList::from(vec![0, 2, 4, 6]);
+
+        *list.first_mut().unwrap() += 1;
+        *list.last_mut().unwrap() += 1;
+        assert_eq!(&list[..], &[1, 2, 4, 7]);
+
+        let mut empty: VariableList<u64, U4> = VariableList::empty();
+        assert_eq!(empty.first_mut(), None);
+        assert_eq!(empty.last_mut(), None);
+    }
+
+    #[test]
+    fn split_first() {
+        let list: VariableList<u64, U4> = VariableList::from(vec![1, 2, 3]);
+        assert_eq!(list.split_first(), Some((&1, &[2, 3][..])));
+
+        let (first, rest) = list.into_split_first().unwrap();
+        assert_eq!(first, 1);
+        assert_eq!(rest, VariableList::<u64, U4>::from(vec![2, 3]));
+
+        let empty: VariableList<u64, U4> = VariableList::empty();
+        assert_eq!(empty.split_first(), None);
+        assert_eq!(empty.into_split_first(), None);
+    }
+
+    #[test]
+    fn drain() {
+        let mut list: VariableList<u64, U8> = VariableList::from(vec![1, 2, 3, 4, 5]);
+
+        let drained: Vec<u64> = list.drain(..2).collect();
+        assert_eq!(drained, vec![1, 2]);
+        assert_eq!(&list[..], &[3, 4, 5]);
+
+        let drained: Vec<u64> = list.drain(1..).collect();
+        assert_eq!(drained, vec![4, 5]);
+        assert_eq!(&list[..], &[3]);
+    }
+
+    #[test]
+    fn partition_point() {
+        let list: VariableList<u64, U8> = VariableList::from(vec![1, 3, 5, 7]);
+        assert_eq!(list.partition_point(|&x| x < 5), 2);
+        assert_eq!(list.partition_point(|&x| x < 1), 0);
+        assert_eq!(list.partition_point(|&x| x < 10), 4);
+    }
+
+    #[test]
+    fn chunks_mut() {
+        let mut list: VariableList<u64, U8> = VariableList::from(vec![0; 5]);
+
+        for (i, chunk) in list.chunks_mut(2).enumerate() {
+            chunk.fill(i as u64);
+        }
+        assert_eq!(&list[..], &[0, 0, 1, 1, 2]);
+
+        list.for_each_chunk_mut(3, |chunk| chunk[0] = 9);
+        assert_eq!(&list[..], &[9, 0, 1, 9, 2]);
+    }
+
+    #[test]
+    fn sum_max_min() {
+        let list: VariableList<u64, U4> = VariableList::from(vec![3, 1, 4, 2]);
+        assert_eq!(list.sum(), 10);
+        assert_eq!(list.max(), Some(&4));
+        assert_eq!(list.min(), Some(&1));
+
+        let empty: VariableList<u64, U0> = VariableList::from(vec![]);
+        assert_eq!(empty.sum(), 0);
+        assert_eq!(empty.max(), None);
+        assert_eq!(empty.min(), None);
+    }
+
+    #[test]
+    fn flatten() {
+        let nested: VariableList<VariableList<u64, U2>, U3> =
+            vec![vec![1, 2].into(), vec![].into(), vec![3].into()].into();
+
+        let flat: VariableList<u64, U3> = super::flatten(nested.clone()).unwrap();
+        assert_eq!(&flat[..], &[1, 2, 3]);
+
+        assert_eq!(
+            super::flatten::<_, _, _, U2>(nested),
+            Err(Error::OutOfBounds { i: 3, len: 2 })
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        let list: VariableList<u8, U4> = "0xdeadbeef".parse().unwrap();
+        assert_eq!(&list[..], &[0xde, 0xad, 0xbe, 0xef]);
+
+        // Uppercase and without a prefix.
+        let list: VariableList<u8, U4> = "DEAD".parse().unwrap();
+        assert_eq!(&list[..], &[0xde, 0xad]);
+
+        let list: VariableList<u8, U4> = "0x".parse().unwrap();
+        assert!(list.is_empty());
+
+        assert!(matches!(
+            "0xdeadb".parse::<VariableList<u8, U4>>(),
+            Err(HexError::InvalidHex(_))
+        ));
+        assert_eq!(
+            "0xdeadbeef00".parse::<VariableList<u8, U4>>(),
+            Err(HexError::InvalidLength(Error::OutOfBounds { i: 5, len: 4 }))
+        );
+    }
+
+    #[test]
+    fn serde_length_bounds() {
+        let list: VariableList<u64, U4> = serde_json::from_str("[1, 2, 3]").unwrap();
+        assert_eq!(&list[..], &[1, 2, 3]);
+
+        let list: VariableList<u64, U4> = serde_json::from_str("[1, 2, 3, 4]").unwrap();
+        assert_eq!(serde_json::to_string(&list).unwrap(), "[1,2,3,4]");
+
+        let err = serde_json::from_str::<VariableList<u64, U4>>("[1, 2, 3, 4, 5]").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("invalid length 5, expected a list of at most 4 elements"));
+    }
+
+    #[test]
+    fn serde_flatten() {
+        use serde_derive::Deserialize;
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Inner {
+            list: VariableList<u64, U4>,
+            fixed: FixedVector<u64, U2>,
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Outer {
+            name: String,
+            #[serde(flatten)]
+            inner: Inner,
+        }
+
+        let outer = Outer {
+            name: "a".into(),
+            inner: Inner {
+                list: vec![1, 2].into(),
+                fixed: vec![3, 4].into(),
+            },
+        };
+        let json = serde_json::to_string(&outer).unwrap();
+        assert_eq!(json, r#"{"name":"a","list":[1,2],"fixed":[3,4]}"#);
+        assert_eq!(serde_json::from_str::<Outer>(&json).unwrap(), outer);
+
+        // Length bounds are still enforced through the flattened representation.
+        serde_json::from_str::<Outer>(r#"{"name":"a","list":[1,2,3,4,5],"fixed":[3,4]}"#)
+            .unwrap_err();
+        serde_json::from_str::<Outer>(r#"{"name":"a","list":[],"fixed":[3]}"#).unwrap_err();
+    }
+
+    #[test]
+    fn serde_large_size_hint() {
+        use serde::de::value::{Error as ValueError, SeqDeserializer};
+
+        // Iterator that claims to have `len` items.
+        struct WonkyIterator<I> {
+            len: usize,
+            iter: I,
+        }
+
+        impl<I: Iterator> Iterator for WonkyIterator<I> {
+            type Item = I::Item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.iter.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+        }
+
+        type List = VariableList<u64, typenum::U1099511627776>;
+
+        let seq = SeqDeserializer::<_, ValueError>::new(WonkyIterator {
+            len: usize::MAX / 16,
+            iter: 0..5_u64,
+        });
+
+        // Don't explode.
+        assert_eq!(List::deserialize(seq).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn eq_iter() {
+        let list: VariableList<u64, U4> = VariableList::from(vec![0, 2, 4, 6]);
+
+        assert!(list.eq_iter((0..4).map(|i| i * 2)));
+        assert!(!list.eq_iter((0..3).map(|i| i * 2)));
+        assert!(!list.eq_iter((0..5).map(|i| i * 2)));
+        assert!(!list.eq_iter(vec![0, 2, 4, 7]));
+    }
+
+    #[test]
+    fn try_from_smallvec() {
+        let small: SmallVec<[u64; 4]> = smallvec::smallvec![1, 2, 3];
+        let list: VariableList<u64, U4> = VariableList::try_from(small).unwrap();
+        assert_eq!(&list[..], &[1, 2, 3]);
+
+        let small: SmallVec<[u64; 4]> = smallvec::smallvec![1, 2, 3, 4, 5];
+        assert_eq!(
+            VariableList::<u64, U4>::try_from(small),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+    }
+
+    #[test]
+    fn iter_rev() {
+        let list: VariableList<u64, U4> = VariableList::from(vec![0, 2, 4, 6]);
+
+        assert_eq!(
+            list.iter_rev().copied().collect::<Vec<_>>(),
+            vec![6, 4, 2, 0]
+        );
+        assert_eq!(list.into_iter_rev().collect::<Vec<_>>(), vec![6, 4, 2, 0]);
+    }
+
     #[test]
     fn encode() {
         let vec: VariableList<u16, U2> = vec![0; 2].into();
@@ -399,6 +1505,44 @@ mod test {
         assert_eq!(<VariableList<u16, U2> as Encode>::ssz_fixed_len(), 4);
     }
 
+    #[test]
+    fn encode_matches_vec() {
+        let list: VariableList<u16, U8> = vec![1, 2, 3].into();
+        assert_eq!(list.as_ssz_bytes(), vec![1_u16, 2, 3].as_ssz_bytes());
+
+        let list: VariableList<VariableList<u8, U4>, U8> =
+            vec![vec![1].into(), vec![].into(), vec![2, 3].into()].into();
+        let vec: Vec<Vec<u8>> = vec![vec![1], vec![], vec![2, 3]];
+        assert_eq!(list.as_ssz_bytes(), vec.as_ssz_bytes());
+    }
+
+    #[test]
+    fn ssz_append_returning_len() {
+        let mut buf = vec![0xff];
+
+        let list: VariableList<u16, U8> = vec![1, 2, 3].into();
+        assert_eq!(list.ssz_append_returning_len(&mut buf), 6);
+
+        let nested: VariableList<VariableList<u8, U4>, U8> =
+            vec![vec![1].into(), vec![].into(), vec![2, 3].into()].into();
+        assert_eq!(
+            nested.ssz_append_returning_len(&mut buf),
+            nested.ssz_bytes_len()
+        );
+
+        let mut expected = vec![0xff];
+        expected.extend(list.as_ssz_bytes());
+        expected.extend(nested.as_ssz_bytes());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn encode_bool_is_not_bit_packed() {
+        let list: VariableList<bool, U8> = vec![true, false, true].into();
+        assert_eq!(list.as_ssz_bytes(), vec![1, 0, 1]);
+        round_trip(list);
+    }
+
     fn round_trip<T: Encode + Decode + std::fmt::Debug + PartialEq>(item: T) {
         let encoded = &item.as_ssz_bytes();
         assert_eq!(item.ssz_bytes_len(), encoded.len());
@@ -411,6 +1555,177 @@ mod test {
         round_trip::<VariableList<u16, U8>>(vec![0; 8].into());
     }
 
+    #[test]
+    fn decode_rejects_partial_item() {
+        assert_eq!(
+            VariableList::<u16, U4>::from_ssz_bytes(&[0; 5]),
+            Err(DecodeError::InvalidByteLength {
+                len: 5,
+                expected: 4
+            })
+        );
+    }
+
+    #[test]
+    fn decode_variable_length_items_rejects_large_first_offset() {
+        type List = VariableList<VariableList<u8, U4>, U4>;
+
+        fn assert_too_many_items(bytes: &[u8], num_items: usize) {
+            match List::from_ssz_bytes(bytes) {
+                Err(DecodeError::BytesInvalid(msg)) => assert!(
+                    msg.ends_with(&format!("{} items exceeds maximum of 4", num_items)),
+                    "{}",
+                    msg
+                ),
+                other => panic!("{:?}", other),
+            }
+        }
+
+        // A lone header whose first offset claims ~1 billion items. Without the early check,
+        // decoding would fail later with `OffsetOutOfBounds`; with it, decoding returns before
+        // reading any items or allocating for them.
+        let header = (u32::MAX - 3).to_le_bytes();
+        assert_too_many_items(&header, (u32::MAX as usize - 3) / 4);
+
+        // The same offset table followed by some item data.
+        let mut bytes = header.to_vec();
+        bytes.extend_from_slice(&[0; 12]);
+        assert_too_many_items(&bytes, (u32::MAX as usize - 3) / 4);
+
+        // A first offset implying exactly `N + 1` items is also rejected.
+        let mut bytes = 20_u32.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&[20, 0, 0, 0].repeat(4));
+        assert_too_many_items(&bytes, 5);
+
+        let list: List = vec![vec![1, 2].into(), vec![3].into()].into();
+        round_trip(list);
+    }
+
+    #[test]
+    fn from_ssz_bytes_with_count() {
+        type List = VariableList<u16, U4>;
+
+        let list: List = vec![1, 2, 3].into();
+        let bytes = list.as_ssz_bytes();
+        assert_eq!(List::from_ssz_bytes_with_count(&bytes, 3), Ok(list));
+        assert_eq!(List::from_ssz_bytes_with_count(&[], 0), Ok(List::empty()));
+
+        assert_eq!(
+            List::from_ssz_bytes_with_count(&bytes, 2),
+            Err(DecodeError::InvalidByteLength {
+                len: 6,
+                expected: 4
+            })
+        );
+        assert_eq!(
+            List::from_ssz_bytes_with_count(&[], 1),
+            Err(DecodeError::InvalidByteLength {
+                len: 0,
+                expected: 2
+            })
+        );
+        assert!(matches!(
+            List::from_ssz_bytes_with_count(&[0; 10], 5),
+            Err(DecodeError::BytesInvalid(_))
+        ));
+
+        type Nested = VariableList<VariableList<u8, U4>, U4>;
+
+        let nested: Nested = vec![vec![1].into(), vec![].into(), vec![2, 3].into()].into();
+        let bytes = nested.as_ssz_bytes();
+        assert_eq!(Nested::from_ssz_bytes_with_count(&bytes, 3), Ok(nested));
+        assert!(matches!(
+            Nested::from_ssz_bytes_with_count(&bytes, 2),
+            Err(DecodeError::BytesInvalid(_))
+        ));
+        assert!(Nested::from_ssz_bytes_with_count(&[], 1).is_err());
+    }
+
+    #[test]
+    fn decode_error_names_type() {
+        let err = VariableList::<u16, U4>::from_ssz_bytes(&[0; 10]).unwrap_err();
+        let DecodeError::BytesInvalid(msg) = err else {
+            panic!("unexpected error {:?}", err);
+        };
+        assert_eq!(msg, "VariableList<u16, 4>: 5 items exceeds maximum of 4");
+
+        // The type-level length is printed as a number, not as its `typenum` expansion.
+        let err = VariableList::<u8, U1099511627776>::from_ssz_bytes_with_count(&[], usize::MAX)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            DecodeError::BytesInvalid(format!(
+                "VariableList<u8, 1099511627776>: {} items exceeds maximum of 1099511627776",
+                usize::MAX
+            ))
+        );
+
+        let err = serde_json::from_str::<VariableList<u16, U4>>("[1, 2, 3, 4, 5]").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected a list of at most 4 elements for VariableList<u16, 4>"));
+    }
+
+    #[test]
+    fn from_ssz_bytes_bounded() {
+        type Inner = VariableList<u8, U8>;
+        type List = VariableList<Inner, U8>;
+
+        // 2 outer elements plus 3 + 4 inner elements.
+        let list: List = vec![vec![1, 2, 3].into(), vec![4, 5, 6, 7].into()].into();
+        let bytes = list.as_ssz_bytes();
+
+        assert_eq!(List::from_ssz_bytes_bounded(&bytes, 9), Ok(list.clone()));
+        assert!(List::from_ssz_bytes_bounded(&bytes, 8).is_err());
+        assert!(List::from_ssz_bytes_bounded(&bytes, 0).is_err());
+
+        // Fixed-length elements are charged too.
+        let fixed: VariableList<FixedVector<u16, U2>, U4> =
+            vec![vec![1, 2].into(), vec![3, 4].into()].into();
+        let bytes = fixed.as_ssz_bytes();
+        assert_eq!(
+            VariableList::from_ssz_bytes_bounded(&bytes, 6),
+            Ok(fixed.clone())
+        );
+        assert!(
+            VariableList::<FixedVector<u16, U2>, U4>::from_ssz_bytes_bounded(&bytes, 5).is_err()
+        );
+
+        let empty = List::empty();
+        assert_eq!(
+            List::from_ssz_bytes_bounded(&empty.as_ssz_bytes(), 0),
+            Ok(empty)
+        );
+    }
+
+    #[test]
+    fn from_ssz_bytes_bounded_matches_plain_decode_errors() {
+        type List = VariableList<u16, U4>;
+
+        // A trailing partial item and too many items are rejected as by `from_ssz_bytes`.
+        for bytes in [&[0; 3][..], &[0; 10][..]] {
+            assert!(List::from_ssz_bytes(bytes).is_err());
+            assert_eq!(
+                List::from_ssz_bytes_bounded(bytes, 100),
+                List::from_ssz_bytes(bytes)
+            );
+        }
+        assert_eq!(
+            List::from_ssz_bytes_bounded(&[0; 3], 100),
+            Err(DecodeError::InvalidByteLength {
+                len: 3,
+                expected: 2
+            })
+        );
+
+        type Nested = VariableList<VariableList<u8, U4>, U4>;
+        let header = (u32::MAX - 3).to_le_bytes();
+        assert_eq!(
+            Nested::from_ssz_bytes_bounded(&header, 100),
+            Nested::from_ssz_bytes(&header)
+        );
+    }
+
     fn root_with_length(bytes: &[u8], len: usize) -> Hash256 {
         let root = merkle_root(bytes, 0);
         tree_hash::mix_in_length(&root, len)
@@ -518,6 +1833,52 @@ mod test {
         }
     }
 
+    #[test]
+    fn checked_tree_hash_root() {
+        let list: VariableList<u64, U4> = VariableList::from(vec![1, 2, 3]);
+        assert_eq!(list.checked_tree_hash_root(), Ok(list.tree_hash_root()));
+
+        let a = A { a: 0, b: 1 };
+        let list: VariableList<A, U13> = VariableList::from(vec![a; 7]);
+        assert_eq!(list.checked_tree_hash_root(), Ok(list.tree_hash_root()));
+
+        // Bypass the constructors to build a list that exceeds its maximum length.
+        let list: VariableList<A, U2> = VariableList {
+            vec: vec![a; 64],
+            _phantom: PhantomData,
+        };
+        assert!(matches!(
+            list.checked_tree_hash_root(),
+            Err(Error::TreeHashError(_))
+        ));
+    }
+
+    #[test]
+    fn try_from_result_iter() {
+        type List = VariableList<u64, U4>;
+
+        let ok: Vec<Result<u64, &str>> = vec![Ok(1), Ok(2), Ok(3)];
+        assert_eq!(
+            List::try_from_result_iter(ok),
+            Ok(List::from(vec![1, 2, 3]))
+        );
+
+        let bad_element: Vec<Result<u64, &str>> = vec![Ok(1), Err("bad"), Ok(3)];
+        assert_eq!(
+            List::try_from_result_iter(bad_element),
+            Err(TryFromResultError::Element("bad"))
+        );
+
+        let too_long = (0..5).map(Ok::<u64, &str>);
+        assert_eq!(
+            List::try_from_result_iter(too_long),
+            Err(TryFromResultError::List(Error::OutOfBounds {
+                i: 5,
+                len: 4
+            }))
+        );
+    }
+
     #[test]
     fn large_list_pre_allocation() {
         use std::iter;
@@ -548,7 +1909,7 @@ mod test {
         type N = U1099511627776;
         type List = VariableList<u64, N>;
 
-        let iter = iter::repeat(1).take(5);
+        let iter = iter::repeat_n(1, 5);
         let wonky_iter = WonkyIterator {
             hint: N::to_usize() / 2,
             iter: iter.clone(),
@@ -574,4 +1935,103 @@ mod test {
         }
         assert_eq!(hashset.len(), 2);
     }
+
+    #[test]
+    fn type_len() {
+        const LEN: usize = VariableList::<u8, U16>::type_len();
+        assert_eq!(LEN, VariableList::<u8, U16>::max_len());
+    }
+
+    #[test]
+    fn mem_usage() {
+        let mut list: VariableList<u64, U16> = VariableList::empty();
+        assert_eq!(list.mem_usage(), 0);
+
+        list.try_reserve(4).unwrap();
+        assert_eq!(list.mem_usage(), list.vec.capacity() * 8);
+        assert!(list.mem_usage() >= 32);
+    }
+
+    #[test]
+    fn clone_from_reuses_allocation() {
+        let mut list: VariableList<u64, U16> = VariableList::new(vec![0; 16]).unwrap();
+        let ptr = list.vec.as_ptr();
+
+        let source: VariableList<u64, U16> = vec![1, 2, 3].into();
+        list.clone_from(&source);
+        assert_eq!(list, source);
+        assert_eq!(list.vec.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn std_hash_ignores_capacity() {
+        use std::hash::BuildHasher;
+
+        let mut vec = Vec::with_capacity(64);
+        vec.extend([1_u32, 2, 3, 4]);
+        let x: VariableList<u32, U16> = VariableList::new(vec).unwrap();
+        let y: VariableList<u32, U16> = VariableList::new(vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(x, y);
+
+        let state = std::collections::hash_map::RandomState::new();
+        assert_eq!(state.hash_one(&x), state.hash_one(&y));
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn bytes_buf_round_trip() {
+        let list = VariableList::<u8, U4>::from_bytes_buf(bytes::Bytes::from(vec![1, 2, 3]));
+        assert_eq!(list, Ok(VariableList::from(vec![1, 2, 3])));
+        assert_eq!(&list.unwrap().into_bytes()[..], &[1, 2, 3]);
+
+        assert_eq!(
+            VariableList::<u8, U4>::from_bytes_buf(bytes::Bytes::from_static(&[0; 5])),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn try_from_par_iter() {
+        use rayon::prelude::*;
+
+        type List = VariableList<u64, U4>;
+
+        assert_eq!(
+            List::try_from_par_iter((0..3_u64).into_par_iter().map(|x| x * 2)),
+            Ok(List::from(vec![0, 2, 4]))
+        );
+        assert_eq!(
+            List::try_from_par_iter(0..5_u64),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+        // Without a known length, the length is checked after collecting.
+        assert_eq!(
+            List::try_from_par_iter((0..10_u64).into_par_iter().filter(|x| x % 2 == 0)),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_short_lists() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        // With a large `N`, the length is limited by the input rather than by `N`.
+        let data = [0xff; 64];
+        let list =
+            VariableList::<u64, U1099511627776>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert!(list.len() < 8);
+
+        let list = VariableList::<u8, U4>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert!(list.len() <= 4);
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn proptest_length_bounds(list in proptest::prelude::any::<VariableList<u64, U4>>()) {
+            assert!(list.len() <= 4);
+        }
+    }
 }